@@ -1,6 +1,10 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Expr, ExprLit, Fields, FnArg, ItemFn, Lit, Meta, Pat,
+    Token, Type,
+};
 
 // This code is based on https://github.com/CosmWasm/cosmwasm/blob/v1.5.3/packages/schema-derive/src/query_responses.rs
 
@@ -86,6 +90,137 @@ pub fn derive_query_responses_hybrid(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+// Prepends permission checks to a handler taking `deps`, `env` and `info` arguments, e.g.
+// #[requires(not_paused, role = "minter")]
+// Supported checks: not_paused, super_admin, super_admin_cached, private, private_or_trusted,
+// role = "..." or role = CONSTANT (repeatable)
+// `deps` can be Deps, DepsMut or a reference to either
+// Only handler functions are supported, execute message variants have to be dispatched to one
+#[proc_macro_attribute]
+pub fn requires(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with Punctuated::<Meta, Token![,]>::parse_terminated);
+    let mut handler = parse_macro_input!(input as ItemFn);
+
+    let Some(deps_type) = arg_type(&handler, "deps") else {
+        return syn::Error::new_spanned(
+            &handler.sig,
+            "#[requires] handler must have a `deps` argument",
+        )
+        .to_compile_error()
+        .into();
+    };
+    for name in ["env", "info"] {
+        if arg_type(&handler, name).is_none() {
+            return syn::Error::new_spanned(
+                &handler.sig,
+                format!("#[requires] handler must have an `{}` argument", name),
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    // Deps is Copy, DepsMut has to be borrowed
+    let (storage, deps_ref) = match deps_type_name(deps_type).as_deref() {
+        Some("Deps") if matches!(deps_type, Type::Reference(_)) => {
+            (quote! { deps.storage }, quote! { &*deps })
+        }
+        Some("Deps") => (quote! { deps.storage }, quote! { &deps }),
+        Some("DepsMut") => (quote! { &*deps.storage }, quote! { &deps.as_ref() }),
+        _ => {
+            return syn::Error::new_spanned(
+                deps_type,
+                "#[requires] `deps` argument must be Deps, DepsMut or a reference to either",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let mut checks = Vec::new();
+    for arg in args {
+        let check = match &arg {
+            Meta::Path(path) if path.is_ident("not_paused") => quote! {
+                ::cw_fetch_std::pausing::ensure_not_paused(#storage, &env)?;
+            },
+            Meta::Path(path) if path.is_ident("super_admin") => quote! {
+                ::cw_fetch_std::permissions::ensure_super_admin(#deps_ref, &env, &info.sender)?;
+            },
+            Meta::Path(path) if path.is_ident("super_admin_cached") => quote! {
+                ::cw_fetch_std::permissions::ensure_super_admin_cached(#deps_ref, &env, &info.sender)?;
+            },
+            Meta::Path(path) if path.is_ident("private") => quote! {
+                ::cw_fetch_std::permissions::ensure_private(&env, &info.sender)?;
+            },
+            Meta::Path(path) if path.is_ident("private_or_trusted") => quote! {
+                ::cw_fetch_std::permissions::ensure_private_or_trusted(#storage, &env, &info.sender)?;
+            },
+            Meta::NameValue(name_value) if name_value.path.is_ident("role") => {
+                let role = &name_value.value;
+                if !matches!(
+                    role,
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(_),
+                        ..
+                    }) | Expr::Path(_)
+                ) {
+                    return syn::Error::new_spanned(
+                        role,
+                        "role must be a string literal or a constant",
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+                quote! {
+                    ::cw_fetch_std::access_control::AccessControl::ensure_has_role(
+                        #storage,
+                        &env,
+                        #role,
+                        &info.sender,
+                    )?;
+                }
+            }
+            _ => {
+                return syn::Error::new_spanned(arg, "Unknown #[requires] check")
+                    .to_compile_error()
+                    .into();
+            }
+        };
+        checks.push(check);
+    }
+
+    let body = &handler.block;
+    handler.block = syn::parse_quote!({
+        #(#checks)*
+        #body
+    });
+
+    TokenStream::from(quote! { #handler })
+}
+
+fn arg_type<'a>(handler: &'a ItemFn, name: &str) -> Option<&'a Type> {
+    handler.sig.inputs.iter().find_map(|arg| match arg {
+        FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+            Pat::Ident(pat_ident) if pat_ident.ident == name => Some(pat_type.ty.as_ref()),
+            _ => None,
+        },
+        FnArg::Receiver(_) => None,
+    })
+}
+
+// Last path segment of the type, references are looked through
+fn deps_type_name(deps_type: &Type) -> Option<String> {
+    match deps_type {
+        Type::Reference(reference) => deps_type_name(&reference.elem),
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
 /// Converts a variant name into the specified casing
 fn to_snake_case(input: &str) -> String {
     // this was stolen from serde for consistent behavior
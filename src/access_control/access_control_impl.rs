@@ -1,44 +1,248 @@
-use crate::access_control::error::{no_role_error, sender_is_not_role_admin_error};
+use crate::access_control::error::{
+    expiration_in_past_error, min_role_holders_error, no_pending_role_grant_error, no_role_error,
+    pending_role_grant_expired_error, role_already_exists_error, role_batch_too_large_error,
+    role_has_pending_grants_error, role_not_empty_error, role_not_found_error,
+    sender_is_not_role_admin_error, two_step_grant_required_error,
+};
 use crate::access_control::storage::AccessControlStorage;
+use crate::access_control::{PendingRoleGrant, RoleData, DEFAULT_ADMIN_ROLE};
 use crate::events::ResponseHandler;
-use crate::permissions::is_super_admin;
+use crate::helpers::iteration_guard::IterationGuard;
+use crate::permissions::{is_super_admin, is_super_admin_cached};
 use cosmwasm_std::{Addr, Deps, Env, StdResult, Storage};
+use cw_utils::Expiration;
+use std::collections::{BTreeMap, BTreeSet};
+
+pub const MAX_ROLE_BATCH_SIZE: u32 = 50;
 
 pub struct AccessControl {}
 
 impl AccessControl {
-    pub fn ensure_is_admin(storage: &dyn Storage, sender: &Addr, role: &str) -> StdResult<()> {
+    pub fn ensure_is_admin(
+        storage: &dyn Storage,
+        env: &Env,
+        sender: &Addr,
+        role: &str,
+    ) -> StdResult<()> {
         let admin_role = AccessControlStorage::get_admin_role(storage, role)?;
 
-        if AccessControlStorage::has_role(storage, &admin_role, sender) {
+        if AccessControlStorage::has_active_role(storage, env, &admin_role, sender) {
             return Ok(());
         }
 
         Err(sender_is_not_role_admin_error(role))
     }
 
+    pub fn create_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: &str,
+        admin_role: &str,
+        description: Option<String>,
+    ) -> StdResult<()> {
+        Self::ensure_is_admin(storage, env, sender, role)?;
+
+        if Self::is_created_role(storage, role)? {
+            return Err(role_already_exists_error(role));
+        }
+
+        AccessControlStorage::create_role(
+            storage,
+            response_handler,
+            role,
+            admin_role,
+            description,
+            env.block.height,
+        )
+    }
+
+    // Only roles without members and pending grants can be deleted, so a pending grant can't
+    // be accepted for a re-created role
+    pub fn delete_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: &str,
+    ) -> StdResult<()> {
+        Self::ensure_is_admin(storage, env, sender, role)?;
+
+        if !Self::is_created_role(storage, role)? {
+            return Err(role_not_found_error(role));
+        }
+
+        if AccessControlStorage::has_members(storage, role) {
+            return Err(role_not_empty_error(role));
+        }
+        if AccessControlStorage::has_pending_role_grants(storage, role) {
+            return Err(role_has_pending_grants_error(role));
+        }
+
+        AccessControlStorage::delete_role(storage, response_handler, role);
+        Ok(())
+    }
+
+    fn is_created_role(storage: &dyn Storage, role: &str) -> StdResult<bool> {
+        Ok(AccessControlStorage::get_role_data(storage, role)?
+            .is_some_and(|data| data.is_created()))
+    }
+
     pub fn grant_role(
         storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: &str,
+        grant_to_address: &Addr,
+    ) -> StdResult<()> {
+        Self::grant_role_until(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role,
+            grant_to_address,
+            Expiration::Never {},
+        )
+    }
+
+    // Grant role which is treated as absent once the expiration is reached
+    pub fn grant_role_until(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: &str,
+        grant_to_address: &Addr,
+        expiration: Expiration,
+    ) -> StdResult<()> {
+        Self::ensure_is_admin(storage, env, sender, role)?;
+        Self::ensure_direct_grant_allowed(storage, env, role, grant_to_address)?;
+
+        if expiration.is_expired(&env.block) {
+            return Err(expiration_in_past_error(&expiration));
+        }
+        // Replacing a permanent grant with a time-bound one removes a holder
+        if !matches!(expiration, Expiration::Never {})
+            && AccessControlStorage::is_permanent_member(storage, role, grant_to_address)
+        {
+            Self::ensure_min_holders_kept(storage, role, 1)?;
+        }
+
+        AccessControlStorage::grant_role(
+            storage,
+            response_handler,
+            role,
+            grant_to_address,
+            &expiration,
+        )
+    }
+
+    // New holders of the default admin role have to accept it, so a mistyped address can't
+    // take over the admin role
+    fn ensure_direct_grant_allowed(
+        storage: &dyn Storage,
+        env: &Env,
+        role: &str,
+        grant_to_address: &Addr,
+    ) -> StdResult<()> {
+        if role == DEFAULT_ADMIN_ROLE
+            && !AccessControlStorage::has_active_role(storage, env, role, grant_to_address)
+        {
+            return Err(two_step_grant_required_error(role));
+        }
+
+        Ok(())
+    }
+
+    // First step of two-step grant, the role is granted once the grantee accepts it.
+    // Proposal can't be accepted after the expiration.
+    pub fn propose_role_grant(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: &str,
+        grant_to_address: &Addr,
+        expiration: Expiration,
+    ) -> StdResult<()> {
+        Self::ensure_is_admin(storage, env, sender, role)?;
+
+        if expiration.is_expired(&env.block) {
+            return Err(expiration_in_past_error(&expiration));
+        }
+
+        AccessControlStorage::propose_role_grant(
+            storage,
+            response_handler,
+            role,
+            grant_to_address,
+            sender,
+            &expiration,
+        )
+    }
+
+    pub fn accept_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: &str,
+    ) -> StdResult<()> {
+        let pending_grant = AccessControlStorage::get_pending_role_grant(storage, role, sender)?
+            .ok_or_else(|| no_pending_role_grant_error(role, sender))?;
+        if pending_grant.expiration.is_expired(&env.block) {
+            return Err(pending_role_grant_expired_error(role, sender));
+        }
+
+        // Proposer must still be the admin at the time of acceptance
+        Self::ensure_is_admin(storage, env, &pending_grant.proposed_by, role)?;
+
+        AccessControlStorage::accept_pending_role_grant(storage, response_handler, role, sender)
+    }
+
+    // Pending grant can be cancelled by the role admin or declined by the grantee
+    pub fn cancel_pending_role_grant(
+        storage: &mut dyn Storage,
+        env: &Env,
         response_handler: &mut ResponseHandler,
         sender: &Addr,
         role: &str,
         grant_to_address: &Addr,
     ) -> StdResult<()> {
-        Self::ensure_is_admin(storage, sender, role)?;
-        AccessControlStorage::grant_role(storage, response_handler, role, grant_to_address)?;
+        if sender != grant_to_address {
+            Self::ensure_is_admin(storage, env, sender, role)?;
+        }
+
+        if AccessControlStorage::get_pending_role_grant(storage, role, grant_to_address)?.is_none()
+        {
+            return Err(no_pending_role_grant_error(role, grant_to_address));
+        }
+
+        AccessControlStorage::cancel_pending_role_grant(
+            storage,
+            response_handler,
+            role,
+            grant_to_address,
+        );
         Ok(())
     }
 
     pub fn revoke_role(
         storage: &mut dyn Storage,
+        env: &Env,
         response_handler: &mut ResponseHandler,
         sender: &Addr,
         role: &str,
         address_to_revoke: &Addr,
     ) -> StdResult<()> {
-        Self::ensure_is_admin(storage, sender, role)?;
-        AccessControlStorage::revoke_role(storage, response_handler, role, address_to_revoke);
-        Ok(())
+        Self::ensure_is_admin(storage, env, sender, role)?;
+        if AccessControlStorage::is_permanent_member(storage, role, address_to_revoke) {
+            Self::ensure_min_holders_kept(storage, role, 1)?;
+        }
+        AccessControlStorage::revoke_role(storage, response_handler, role, address_to_revoke)
     }
 
     pub fn renounce_role(
@@ -47,24 +251,143 @@ impl AccessControl {
         sender: &Addr,
         role: &str,
     ) -> StdResult<()> {
-        Self::ensure_has_role(storage, role, sender)?;
-        AccessControlStorage::revoke_role(storage, response_handler, role, sender);
+        // Expired grants can be renounced as well to clear them from storage
+        if !AccessControlStorage::has_role(storage, role, sender) {
+            return Err(no_role_error(sender, Some(role)));
+        }
+        if AccessControlStorage::is_permanent_member(storage, role, sender) {
+            Self::ensure_min_holders_kept(storage, role, 1)?;
+        }
+        AccessControlStorage::revoke_role(storage, response_handler, role, sender)
+    }
+
+    // All changes are validated before any of them is applied
+    pub fn grant_roles(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        grants: Vec<(String, Addr)>,
+    ) -> StdResult<()> {
+        Self::ensure_is_batch_admin(storage, env, sender, &grants)?;
+        for (role, grant_to_address) in &grants {
+            Self::ensure_direct_grant_allowed(storage, env, role, grant_to_address)?;
+        }
+
+        for (role, grant_to_address) in &grants {
+            AccessControlStorage::grant_role(
+                storage,
+                response_handler,
+                role,
+                grant_to_address,
+                &Expiration::Never {},
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // All changes are validated before any of them is applied
+    pub fn revoke_roles(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        revocations: Vec<(String, Addr)>,
+    ) -> StdResult<()> {
+        Self::ensure_is_batch_admin(storage, env, sender, &revocations)?;
+
+        let mut removed_holders: BTreeMap<&str, BTreeSet<&Addr>> = BTreeMap::new();
+        for (role, address_to_revoke) in &revocations {
+            if AccessControlStorage::is_permanent_member(storage, role, address_to_revoke) {
+                removed_holders
+                    .entry(role)
+                    .or_default()
+                    .insert(address_to_revoke);
+            }
+        }
+        for (role, holders) in &removed_holders {
+            Self::ensure_min_holders_kept(storage, role, holders.len() as u32)?;
+        }
+
+        for (role, address_to_revoke) in &revocations {
+            AccessControlStorage::revoke_role(storage, response_handler, role, address_to_revoke)?;
+        }
+
+        Ok(())
+    }
+
+    fn ensure_is_batch_admin(
+        storage: &dyn Storage,
+        env: &Env,
+        sender: &Addr,
+        changes: &[(String, Addr)],
+    ) -> StdResult<()> {
+        // Guard stops at max_iterations, so one extra iteration is allowed
+        let mut iteration_guard = IterationGuard::new(MAX_ROLE_BATCH_SIZE + 1);
+        let mut checked_roles = BTreeSet::new();
+
+        for (role, _) in changes {
+            if iteration_guard.next_iteration().is_stopped() {
+                return Err(role_batch_too_large_error(MAX_ROLE_BATCH_SIZE));
+            }
+
+            if checked_roles.insert(role.as_str()) {
+                Self::ensure_is_admin(storage, env, sender, role)?;
+            }
+        }
+
         Ok(())
     }
 
     pub fn change_admin_role(
         storage: &mut dyn Storage,
+        env: &Env,
         response_handler: &mut ResponseHandler,
         sender: &Addr,
         role: &str,
         new_admin_role: &str,
     ) -> StdResult<()> {
-        Self::ensure_is_admin(storage, sender, role)?;
+        Self::ensure_is_admin(storage, env, sender, role)?;
         AccessControlStorage::set_admin_role(storage, response_handler, role, new_admin_role)
     }
 
-    pub fn ensure_has_role(storage: &dyn Storage, role: &str, address: &Addr) -> StdResult<()> {
-        if !AccessControlStorage::has_role(storage, role, address) {
+    // Opt-in policy preventing revoke and renounce from dropping the role below min_holders
+    pub fn set_min_role_holders(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: &str,
+        min_holders: u32,
+    ) -> StdResult<()> {
+        Self::ensure_is_admin(storage, env, sender, role)?;
+        AccessControlStorage::set_min_holders(storage, response_handler, role, min_holders)
+    }
+
+    // Only holders with grants without expiration count towards the minimum,
+    // time-bound grants lapse on their own and can't keep the role alive
+    fn ensure_min_holders_kept(storage: &dyn Storage, role: &str, removed: u32) -> StdResult<()> {
+        let min_holders = AccessControlStorage::get_min_holders(storage, role)?;
+        if min_holders == 0 {
+            return Ok(());
+        }
+
+        let permanent_count = AccessControlStorage::get_permanent_member_count(storage, role)?;
+        if permanent_count < min_holders.saturating_add(removed) {
+            return Err(min_role_holders_error(role, min_holders));
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_has_role(
+        storage: &dyn Storage,
+        env: &Env,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<()> {
+        if !AccessControlStorage::has_active_role(storage, env, role, address) {
             return Err(no_role_error(address, Some(role)));
         }
 
@@ -77,7 +400,7 @@ impl AccessControl {
         role: &str,
         address: &Addr,
     ) -> StdResult<()> {
-        if AccessControlStorage::has_role(deps.storage, role, address)
+        if AccessControlStorage::has_active_role(deps.storage, env, role, address)
             || is_super_admin(deps, env, address)?
         {
             Ok(())
@@ -86,13 +409,31 @@ impl AccessControl {
         }
     }
 
+    // Same as ensure_has_role_or_superadmin but uses the synced super-admin,
+    // which is stale after admin changes until sync_super_admin is called again
+    pub fn ensure_has_role_or_superadmin_cached(
+        deps: &Deps,
+        env: &Env,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<()> {
+        if AccessControlStorage::has_active_role(deps.storage, env, role, address)
+            || is_super_admin_cached(deps, env, address)?
+        {
+            Ok(())
+        } else {
+            Err(no_role_error(address, Some(role)))
+        }
+    }
+
     pub fn ensure_has_any_role(
         storage: &dyn Storage,
+        env: &Env,
         roles: Vec<&str>,
         address: &Addr,
     ) -> StdResult<()> {
         for role in roles {
-            if AccessControlStorage::has_role(storage, role, address) {
+            if AccessControlStorage::has_active_role(storage, env, role, address) {
                 return Ok(());
             }
         }
@@ -104,8 +445,78 @@ impl AccessControl {
         AccessControlStorage::get_admin_role(storage, role)
     }
 
-    pub fn has_role(storage: &dyn Storage, role: &str, address: &Addr) -> bool {
-        AccessControlStorage::has_role(storage, role, address)
+    // Returns default role data for roles which were never configured
+    pub fn role_info(storage: &dyn Storage, role: &str) -> StdResult<RoleData> {
+        Ok(AccessControlStorage::get_role_data(storage, role)?.unwrap_or_default())
+    }
+
+    pub fn has_role(storage: &dyn Storage, env: &Env, role: &str, address: &Addr) -> bool {
+        AccessControlStorage::has_active_role(storage, env, role, address)
+    }
+
+    // Returns None if address was never granted the role, expired grants are returned as well
+    pub fn role_expiration(
+        storage: &dyn Storage,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<Option<Expiration>> {
+        AccessControlStorage::get_role_expiration(storage, role, address)
+    }
+
+    // Expired grants are counted until they are revoked
+    pub fn role_member_count(storage: &dyn Storage, role: &str) -> StdResult<u32> {
+        AccessControlStorage::get_member_count(storage, role)
+    }
+
+    // Holders counted towards the minimum holders
+    pub fn permanent_role_member_count(storage: &dyn Storage, role: &str) -> StdResult<u32> {
+        AccessControlStorage::get_permanent_member_count(storage, role)
+    }
+
+    pub fn min_role_holders(storage: &dyn Storage, role: &str) -> StdResult<u32> {
+        AccessControlStorage::get_min_holders(storage, role)
+    }
+
+    pub fn pending_role_grant(
+        storage: &dyn Storage,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<Option<PendingRoleGrant>> {
+        AccessControlStorage::get_pending_role_grant(storage, role, address)
+    }
+
+    pub fn range_pending_role_grants<'a>(
+        storage: &'a dyn Storage,
+        role: &str,
+        start_after: Option<&Addr>,
+    ) -> Box<dyn Iterator<Item = StdResult<(Addr, PendingRoleGrant)>> + 'a> {
+        AccessControlStorage::range_pending_role_grants(storage, role, start_after)
+    }
+
+    // Expired grants are listed until they are revoked
+    pub fn range_roles_of<'a>(
+        storage: &'a dyn Storage,
+        address: &Addr,
+        start_after: Option<&str>,
+    ) -> Box<dyn Iterator<Item = StdResult<String>> + 'a> {
+        AccessControlStorage::range_roles_of(storage, address, start_after)
+    }
+
+    // Expired grants are listed until they are revoked
+    pub fn range_addresses_with_role<'a>(
+        storage: &'a dyn Storage,
+        role: &str,
+        start_after: Option<&Addr>,
+    ) -> StdResult<Box<dyn Iterator<Item = StdResult<Addr>> + 'a>> {
+        AccessControlStorage::range_addresses_with_role(storage, role, start_after)
+    }
+
+    // Lists created roles and roles with non-default admin role
+    pub fn range_roles<'a>(
+        storage: &'a dyn Storage,
+        start_after: Option<&str>,
+    ) -> Box<dyn Iterator<Item = StdResult<String>> + 'a> {
+        AccessControlStorage::range_roles(storage, start_after)
     }
 
     pub fn _grant_role_unrestricted(
@@ -114,15 +525,21 @@ impl AccessControl {
         role: &str,
         grant_to_address: &Addr,
     ) -> StdResult<()> {
-        AccessControlStorage::grant_role(storage, response_handler, role, grant_to_address)
+        AccessControlStorage::grant_role(
+            storage,
+            response_handler,
+            role,
+            grant_to_address,
+            &Expiration::Never {},
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::access_control::DEFAULT_ADMIN_ROLE;
-    use crate::testing::helpers::deps_with_creator;
+    use crate::access_control::{init_access_control_schema_version, DEFAULT_ADMIN_ROLE};
+    use crate::testing::helpers::{assert_err, deps_with_creator, mock_env_with_height};
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
 
     const ROLE_A: &str = "role_a";
@@ -130,6 +547,7 @@ mod tests {
 
     #[test]
     fn get_set_admin_role() {
+        let env = mock_env();
         let mut deps = mock_dependencies();
         let creator = Addr::unchecked("owner".to_string());
 
@@ -148,6 +566,7 @@ mod tests {
 
         assert!(AccessControl::change_admin_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             &ROLE_A,
@@ -181,6 +600,7 @@ mod tests {
         // Make creator the admin
         assert!(AccessControl::grant_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             DEFAULT_ADMIN_ROLE,
@@ -191,6 +611,7 @@ mod tests {
         // Admin should be able to grant role
         assert!(AccessControl::grant_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             &str_role_a,
@@ -201,6 +622,7 @@ mod tests {
         // Ensure the user has the role
         assert!(AccessControl::has_role(
             deps.as_mut().storage,
+            &env,
             &str_role_a,
             &user,
         ));
@@ -226,6 +648,7 @@ mod tests {
         // Admin should be able to grant role
         assert!(AccessControl::grant_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             &str_role_a,
@@ -236,6 +659,7 @@ mod tests {
         // Ensure the user has the role
         assert!(AccessControl::has_role(
             deps.as_mut().storage,
+            &env,
             &str_role_a,
             &user,
         ));
@@ -243,6 +667,7 @@ mod tests {
         // Admin should be able to revoke role
         assert!(AccessControl::revoke_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             &str_role_a,
@@ -253,6 +678,7 @@ mod tests {
         // Ensure the user no longer has the role
         assert!(!AccessControl::has_role(
             deps.as_mut().storage,
+            &env,
             &str_role_a,
             &user,
         ));
@@ -285,6 +711,7 @@ mod tests {
         // Change the role admin
         assert!(AccessControl::change_admin_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             &str_role_a,
@@ -307,7 +734,9 @@ mod tests {
         let env = mock_env();
         let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
 
-        assert!(AccessControl::ensure_is_admin(deps.as_ref().storage, &creator, &ROLE_A).is_err());
+        assert!(
+            AccessControl::ensure_is_admin(deps.as_ref().storage, &env, &creator, &ROLE_A).is_err()
+        );
 
         // Give creator admin role
         assert!(AccessControl::_grant_role_unrestricted(
@@ -319,15 +748,20 @@ mod tests {
         .is_ok());
 
         // Ensure role admin passes for the correct admin
-        assert!(AccessControl::ensure_is_admin(deps.as_ref().storage, &creator, &ROLE_A).is_ok());
+        assert!(
+            AccessControl::ensure_is_admin(deps.as_ref().storage, &env, &creator, &ROLE_A).is_ok()
+        );
 
         // Ensure role admin fails for someone who is not the admin
-        assert!(AccessControl::ensure_is_admin(deps.as_ref().storage, &other, &ROLE_A).is_err());
+        assert!(
+            AccessControl::ensure_is_admin(deps.as_ref().storage, &env, &other, &ROLE_A).is_err()
+        );
 
         // Test revoke
         assert_eq!(
             AccessControl::revoke_role(
                 deps.as_mut().storage,
+                &env,
                 &mut ResponseHandler::default(),
                 &other,
                 &DEFAULT_ADMIN_ROLE,
@@ -338,6 +772,7 @@ mod tests {
         );
         assert!(AccessControl::revoke_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             &DEFAULT_ADMIN_ROLE,
@@ -345,7 +780,9 @@ mod tests {
         )
         .is_ok());
 
-        assert!(AccessControl::ensure_is_admin(deps.as_ref().storage, &creator, &ROLE_A).is_err());
+        assert!(
+            AccessControl::ensure_is_admin(deps.as_ref().storage, &env, &creator, &ROLE_A).is_err()
+        );
     }
 
     #[test]
@@ -376,6 +813,7 @@ mod tests {
 
         assert!(AccessControl::grant_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             &ROLE_A,
@@ -385,6 +823,7 @@ mod tests {
 
         assert!(AccessControl::change_admin_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &creator,
             DEFAULT_ADMIN_ROLE,
@@ -395,6 +834,7 @@ mod tests {
         assert_eq!(
             AccessControl::revoke_role(
                 deps.as_mut().storage,
+                &env,
                 &mut ResponseHandler::default(),
                 &creator,
                 DEFAULT_ADMIN_ROLE,
@@ -415,6 +855,7 @@ mod tests {
         assert_eq!(
             AccessControl::revoke_role(
                 deps.as_mut().storage,
+                &env,
                 &mut ResponseHandler::default(),
                 &user2,
                 DEFAULT_ADMIN_ROLE,
@@ -426,6 +867,7 @@ mod tests {
 
         assert!(AccessControl::revoke_role(
             deps.as_mut().storage,
+            &env,
             &mut ResponseHandler::default(),
             &user1,
             DEFAULT_ADMIN_ROLE,
@@ -433,4 +875,1040 @@ mod tests {
         )
         .is_ok());
     }
+
+    #[test]
+    fn test_range_addresses_with_role() {
+        let mut deps = mock_dependencies();
+        let user1 = Addr::unchecked("user1".to_string());
+        let user2 = Addr::unchecked("user2".to_string());
+        let user3 = Addr::unchecked("user3".to_string());
+
+        for user in [&user1, &user2, &user3] {
+            assert!(AccessControl::_grant_role_unrestricted(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                ROLE_A,
+                user
+            )
+            .is_ok());
+        }
+
+        let members: Vec<Addr> =
+            AccessControl::range_addresses_with_role(deps.as_ref().storage, ROLE_A, None)
+                .unwrap()
+                .map(|addr| addr.unwrap())
+                .collect();
+        assert_eq!(members, vec![user1.clone(), user2.clone(), user3.clone()]);
+
+        let members: Vec<Addr> =
+            AccessControl::range_addresses_with_role(deps.as_ref().storage, ROLE_A, Some(&user1))
+                .unwrap()
+                .map(|addr| addr.unwrap())
+                .collect();
+        assert_eq!(members, vec![user2, user3]);
+
+        assert_eq!(
+            AccessControl::range_addresses_with_role(deps.as_ref().storage, ROLE_B, None)
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_range_roles() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let creator = Addr::unchecked("owner".to_string());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEFAULT_ADMIN_ROLE,
+            &creator
+        )
+        .is_ok());
+
+        // Roles with default admin role are not stored
+        assert_eq!(
+            AccessControl::range_roles(deps.as_ref().storage, None).count(),
+            0
+        );
+
+        for role in [ROLE_A, ROLE_B] {
+            assert!(AccessControl::change_admin_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                role,
+                "other_admin"
+            )
+            .is_ok());
+        }
+
+        let roles: Vec<String> = AccessControl::range_roles(deps.as_ref().storage, None)
+            .map(|role| role.unwrap())
+            .collect();
+        assert_eq!(roles, vec![ROLE_A.to_string(), ROLE_B.to_string()]);
+
+        let roles: Vec<String> = AccessControl::range_roles(deps.as_ref().storage, Some(ROLE_A))
+            .map(|role| role.unwrap())
+            .collect();
+        assert_eq!(roles, vec![ROLE_B.to_string()]);
+    }
+
+    #[test]
+    fn test_roles_of() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(
+            AccessControl::range_roles_of(deps.as_ref().storage, &user, None)
+                .next()
+                .is_none()
+        );
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEFAULT_ADMIN_ROLE,
+            &creator
+        )
+        .is_ok());
+
+        for role in [ROLE_B, ROLE_A] {
+            assert!(AccessControl::grant_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                role,
+                &user
+            )
+            .is_ok());
+        }
+
+        assert_eq!(
+            AccessControl::range_roles_of(deps.as_ref().storage, &user, None)
+                .collect::<StdResult<Vec<String>>>()
+                .unwrap(),
+            vec![ROLE_A.to_string(), ROLE_B.to_string()]
+        );
+        assert_eq!(
+            AccessControl::range_roles_of(deps.as_ref().storage, &user, Some(ROLE_A))
+                .collect::<StdResult<Vec<String>>>()
+                .unwrap(),
+            vec![ROLE_B.to_string()]
+        );
+        assert_eq!(
+            AccessControl::range_roles_of(deps.as_ref().storage, &creator, None)
+                .collect::<StdResult<Vec<String>>>()
+                .unwrap(),
+            vec![DEFAULT_ADMIN_ROLE.to_string()]
+        );
+
+        assert!(AccessControl::revoke_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &user
+        )
+        .is_ok());
+
+        assert!(AccessControl::renounce_role(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            &user,
+            ROLE_B
+        )
+        .is_ok());
+
+        assert!(
+            AccessControl::range_roles_of(deps.as_ref().storage, &user, None)
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_grant_role_until() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env_with_height(100);
+        let env_expired = mock_env_with_height(110);
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEFAULT_ADMIN_ROLE,
+            &creator
+        )
+        .is_ok());
+
+        // Expiration in the past is rejected
+        assert_err(
+            &AccessControl::grant_role_until(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                ROLE_A,
+                &user,
+                Expiration::AtHeight(100),
+            ),
+            &expiration_in_past_error(&Expiration::AtHeight(100)),
+        );
+
+        assert!(AccessControl::grant_role_until(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &user,
+            Expiration::AtHeight(110)
+        )
+        .is_ok());
+
+        assert_eq!(
+            AccessControl::role_expiration(deps.as_ref().storage, ROLE_A, &user).unwrap(),
+            Some(Expiration::AtHeight(110))
+        );
+        assert!(AccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            ROLE_A,
+            &user
+        ));
+        assert!(AccessControl::ensure_has_role(deps.as_ref().storage, &env, ROLE_A, &user).is_ok());
+
+        // Expired grant is treated as absent
+        assert!(!AccessControl::has_role(
+            deps.as_ref().storage,
+            &env_expired,
+            ROLE_A,
+            &user
+        ));
+        assert_err(
+            &AccessControl::ensure_has_role(deps.as_ref().storage, &env_expired, ROLE_A, &user),
+            &no_role_error(&user, Some(ROLE_A)),
+        );
+
+        // Expired grant can still be renounced
+        assert!(AccessControl::renounce_role(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            &user,
+            ROLE_A
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::role_expiration(deps.as_ref().storage, ROLE_A, &user).unwrap(),
+            None
+        );
+        assert_err(
+            &AccessControl::renounce_role(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                &user,
+                ROLE_A,
+            ),
+            &no_role_error(&user, Some(ROLE_A)),
+        );
+
+        // Permanent grant clears the expiration
+        assert!(AccessControl::grant_role(
+            deps.as_mut().storage,
+            &env_expired,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &user
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::role_expiration(deps.as_ref().storage, ROLE_A, &user).unwrap(),
+            Some(Expiration::Never {})
+        );
+        assert!(AccessControl::has_role(
+            deps.as_ref().storage,
+            &env_expired,
+            ROLE_A,
+            &user
+        ));
+
+        assert!(AccessControl::revoke_role(
+            deps.as_mut().storage,
+            &env_expired,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &user
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::role_expiration(deps.as_ref().storage, ROLE_A, &user).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_two_step_grant() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+        let other = Addr::unchecked("other".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEFAULT_ADMIN_ROLE,
+            &creator
+        )
+        .is_ok());
+
+        // Only admin can propose
+        assert_err(
+            &AccessControl::propose_role_grant(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &other,
+                DEFAULT_ADMIN_ROLE,
+                &user,
+                Expiration::Never {},
+            ),
+            &sender_is_not_role_admin_error(DEFAULT_ADMIN_ROLE),
+        );
+
+        assert!(AccessControl::propose_role_grant(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            &user,
+            Expiration::Never {}
+        )
+        .is_ok());
+
+        // Role is not granted until accepted
+        assert!(!AccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            DEFAULT_ADMIN_ROLE,
+            &user
+        ));
+        assert_eq!(
+            AccessControl::pending_role_grant(deps.as_ref().storage, DEFAULT_ADMIN_ROLE, &user)
+                .unwrap(),
+            Some(PendingRoleGrant {
+                proposed_by: creator.clone(),
+                expiration: Expiration::Never {},
+            })
+        );
+
+        // Only the grantee can accept
+        assert_err(
+            &AccessControl::accept_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &other,
+                DEFAULT_ADMIN_ROLE,
+            ),
+            &no_pending_role_grant_error(DEFAULT_ADMIN_ROLE, &other),
+        );
+
+        assert!(AccessControl::accept_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &user,
+            DEFAULT_ADMIN_ROLE
+        )
+        .is_ok());
+
+        assert!(AccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            DEFAULT_ADMIN_ROLE,
+            &user
+        ));
+        assert!(AccessControl::pending_role_grant(
+            deps.as_ref().storage,
+            DEFAULT_ADMIN_ROLE,
+            &user
+        )
+        .unwrap()
+        .is_none());
+
+        // Cancelled grant can't be accepted
+        assert!(AccessControl::propose_role_grant(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &other,
+            Expiration::Never {}
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::range_pending_role_grants(deps.as_ref().storage, ROLE_A, None)
+                .map(|grant| grant.unwrap().0)
+                .collect::<Vec<Addr>>(),
+            vec![other.clone()]
+        );
+
+        assert!(AccessControl::cancel_pending_role_grant(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &user,
+            ROLE_A,
+            &other
+        )
+        .is_ok());
+
+        assert_err(
+            &AccessControl::accept_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &other,
+                ROLE_A,
+            ),
+            &no_pending_role_grant_error(ROLE_A, &other),
+        );
+        assert!(!AccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            ROLE_A,
+            &other
+        ));
+        // New admins can't be granted directly
+        assert_err(
+            &AccessControl::grant_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                DEFAULT_ADMIN_ROLE,
+                &other,
+            ),
+            &two_step_grant_required_error(DEFAULT_ADMIN_ROLE),
+        );
+
+        // Expired proposal can't be accepted
+        let expiration = Expiration::AtHeight(env.block.height + 10);
+        assert_err(
+            &AccessControl::propose_role_grant(
+                deps.as_mut().storage,
+                &mock_env_with_height(env.block.height + 10),
+                &mut ResponseHandler::default(),
+                &creator,
+                ROLE_A,
+                &other,
+                expiration,
+            ),
+            &expiration_in_past_error(&expiration),
+        );
+        assert!(AccessControl::propose_role_grant(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &other,
+            expiration
+        )
+        .is_ok());
+        assert_err(
+            &AccessControl::accept_role(
+                deps.as_mut().storage,
+                &mock_env_with_height(env.block.height + 10),
+                &mut ResponseHandler::default(),
+                &other,
+                ROLE_A,
+            ),
+            &pending_role_grant_expired_error(ROLE_A, &other),
+        );
+        assert!(AccessControl::accept_role(
+            deps.as_mut().storage,
+            &mock_env_with_height(env.block.height + 9),
+            &mut ResponseHandler::default(),
+            &other,
+            ROLE_A
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_min_role_holders() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+        // Counts are read from storage
+        assert!(init_access_control_schema_version(deps.as_mut().storage).is_ok());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEFAULT_ADMIN_ROLE,
+            &creator
+        )
+        .is_ok());
+
+        assert!(AccessControl::propose_role_grant(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            &user,
+            Expiration::Never {}
+        )
+        .is_ok());
+        assert!(AccessControl::accept_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &user,
+            DEFAULT_ADMIN_ROLE
+        )
+        .is_ok());
+
+        // Re-granting does not change the count
+        assert!(AccessControl::grant_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            &user
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            2
+        );
+
+        assert!(AccessControl::set_min_role_holders(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            1
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::min_role_holders(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            1
+        );
+
+        assert!(AccessControl::renounce_role(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            &user,
+            DEFAULT_ADMIN_ROLE
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            1
+        );
+
+        // Last admin can neither renounce nor be revoked
+        assert_err(
+            &AccessControl::renounce_role(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                &creator,
+                DEFAULT_ADMIN_ROLE,
+            ),
+            &min_role_holders_error(DEFAULT_ADMIN_ROLE, 1),
+        );
+        assert_err(
+            &AccessControl::revoke_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                DEFAULT_ADMIN_ROLE,
+                &creator,
+            ),
+            &min_role_holders_error(DEFAULT_ADMIN_ROLE, 1),
+        );
+
+        // Revoking address without the role is not affected by the policy
+        assert!(AccessControl::revoke_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            &user
+        )
+        .is_ok());
+
+        assert!(AccessControl::set_min_role_holders(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            0
+        )
+        .is_ok());
+        assert!(AccessControl::renounce_role(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_min_role_holders_ignores_time_bound_grants() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env_with_height(100);
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+        // Counts are read from storage
+        assert!(init_access_control_schema_version(deps.as_mut().storage).is_ok());
+
+        for admin in [&creator, &user] {
+            assert!(AccessControl::_grant_role_unrestricted(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                DEFAULT_ADMIN_ROLE,
+                admin
+            )
+            .is_ok());
+        }
+        assert!(AccessControl::set_min_role_holders(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            1
+        )
+        .is_ok());
+        assert!(AccessControl::grant_role_until(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            &user,
+            Expiration::AtHeight(110)
+        )
+        .is_ok());
+
+        // Time-bound admin is a member but doesn't count as a holder
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            2
+        );
+        assert_eq!(
+            AccessControl::permanent_role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE)
+                .unwrap(),
+            1
+        );
+        assert_err(
+            &AccessControl::renounce_role(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                &creator,
+                DEFAULT_ADMIN_ROLE,
+            ),
+            &min_role_holders_error(DEFAULT_ADMIN_ROLE, 1),
+        );
+        assert_err(
+            &AccessControl::revoke_roles(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                vec![(DEFAULT_ADMIN_ROLE.to_string(), creator.clone())],
+            ),
+            &min_role_holders_error(DEFAULT_ADMIN_ROLE, 1),
+        );
+
+        // Last permanent grant can't be replaced by a time-bound one
+        assert_err(
+            &AccessControl::grant_role_until(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                DEFAULT_ADMIN_ROLE,
+                &creator,
+                Expiration::AtHeight(110),
+            ),
+            &min_role_holders_error(DEFAULT_ADMIN_ROLE, 1),
+        );
+
+        // Time-bound grant can be removed at any time
+        assert!(AccessControl::revoke_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            DEFAULT_ADMIN_ROLE,
+            &user
+        )
+        .is_ok());
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            1
+        );
+        assert_eq!(
+            AccessControl::permanent_role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE)
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_batch_grant_revoke() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user1 = Addr::unchecked("user1".to_string());
+        let user2 = Addr::unchecked("user2".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEFAULT_ADMIN_ROLE,
+            &creator
+        )
+        .is_ok());
+        assert!(AccessControl::change_admin_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_B,
+            ROLE_A
+        )
+        .is_ok());
+
+        let grants = vec![
+            (ROLE_A.to_string(), user1.clone()),
+            (ROLE_A.to_string(), user2.clone()),
+            (ROLE_B.to_string(), user2.clone()),
+        ];
+
+        // Creator is not admin of ROLE_B so nothing is granted
+        assert_err(
+            &AccessControl::grant_roles(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                grants.clone(),
+            ),
+            &sender_is_not_role_admin_error(ROLE_B),
+        );
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, ROLE_A).unwrap(),
+            0
+        );
+
+        let mut response_handler = ResponseHandler::default();
+        assert!(AccessControl::grant_roles(
+            deps.as_mut().storage,
+            &env,
+            &mut response_handler,
+            &creator,
+            grants[..2].to_vec()
+        )
+        .is_ok());
+        assert_eq!(response_handler.into_response().events.len(), 2);
+        assert!(AccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            ROLE_A,
+            &user1
+        ));
+        assert!(AccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            ROLE_A,
+            &user2
+        ));
+
+        assert!(AccessControl::set_min_role_holders(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            1
+        )
+        .is_ok());
+
+        // Revoking both holders would violate the policy
+        assert_err(
+            &AccessControl::revoke_roles(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                grants[..2].to_vec(),
+            ),
+            &min_role_holders_error(ROLE_A, 1),
+        );
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, ROLE_A).unwrap(),
+            2
+        );
+
+        assert!(AccessControl::revoke_roles(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            grants[..1].to_vec()
+        )
+        .is_ok());
+        assert!(!AccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            ROLE_A,
+            &user1
+        ));
+
+        // Batch size is bounded
+        let too_large = (0..=MAX_ROLE_BATCH_SIZE)
+            .map(|i| (ROLE_A.to_string(), Addr::unchecked(format!("user{}", i))))
+            .collect();
+        assert_err(
+            &AccessControl::grant_roles(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                too_large,
+            ),
+            &role_batch_too_large_error(MAX_ROLE_BATCH_SIZE),
+        );
+
+        // New admins have to accept the role
+        assert_err(
+            &AccessControl::grant_roles(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                vec![
+                    (ROLE_A.to_string(), user1.clone()),
+                    (DEFAULT_ADMIN_ROLE.to_string(), user1.clone()),
+                ],
+            ),
+            &two_step_grant_required_error(DEFAULT_ADMIN_ROLE),
+        );
+        assert!(!AccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            ROLE_A,
+            &user1
+        ));
+    }
+
+    #[test]
+    fn test_create_delete_role() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env_with_height(100);
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEFAULT_ADMIN_ROLE,
+            &creator
+        )
+        .is_ok());
+
+        assert_eq!(
+            AccessControl::role_info(deps.as_ref().storage, ROLE_A).unwrap(),
+            RoleData::default()
+        );
+
+        assert!(AccessControl::create_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            DEFAULT_ADMIN_ROLE,
+            Some("Role A".to_string())
+        )
+        .is_ok());
+
+        assert_eq!(
+            AccessControl::role_info(deps.as_ref().storage, ROLE_A).unwrap(),
+            RoleData {
+                admin_role: DEFAULT_ADMIN_ROLE.to_string(),
+                description: Some("Role A".to_string()),
+                created_at: Some(100),
+            }
+        );
+        assert_eq!(
+            AccessControl::range_roles(deps.as_ref().storage, None)
+                .map(|role| role.unwrap())
+                .collect::<Vec<String>>(),
+            vec![ROLE_A.to_string()]
+        );
+
+        assert_err(
+            &AccessControl::create_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                ROLE_A,
+                DEFAULT_ADMIN_ROLE,
+                None,
+            ),
+            &role_already_exists_error(ROLE_A),
+        );
+
+        // Created role keeps its data when admin role changes back to default
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            ROLE_B,
+            &creator
+        )
+        .is_ok());
+        for admin_role in [ROLE_B, DEFAULT_ADMIN_ROLE] {
+            assert!(AccessControl::change_admin_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                ROLE_A,
+                admin_role
+            )
+            .is_ok());
+        }
+        assert_eq!(
+            AccessControl::role_info(deps.as_ref().storage, ROLE_A)
+                .unwrap()
+                .description,
+            Some("Role A".to_string())
+        );
+
+        assert!(AccessControl::grant_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &user
+        )
+        .is_ok());
+
+        assert_err(
+            &AccessControl::delete_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                ROLE_A,
+            ),
+            &role_not_empty_error(ROLE_A),
+        );
+
+        assert!(AccessControl::revoke_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &user
+        )
+        .is_ok());
+        assert!(AccessControl::propose_role_grant(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &user,
+            Expiration::Never {}
+        )
+        .is_ok());
+
+        // Pending grant has to be cancelled before the deletion
+        assert_err(
+            &AccessControl::delete_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                ROLE_A,
+            ),
+            &role_has_pending_grants_error(ROLE_A),
+        );
+        assert!(AccessControl::cancel_pending_role_grant(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A,
+            &user
+        )
+        .is_ok());
+        assert!(AccessControl::delete_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            ROLE_A
+        )
+        .is_ok());
+
+        assert_eq!(
+            AccessControl::role_info(deps.as_ref().storage, ROLE_A).unwrap(),
+            RoleData::default()
+        );
+        assert_err(
+            &AccessControl::delete_role(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &creator,
+                ROLE_A,
+            ),
+            &role_not_found_error(ROLE_A),
+        );
+    }
 }
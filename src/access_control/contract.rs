@@ -1,7 +1,9 @@
-use crate::access_control::AccessControl;
+use crate::access_control::{AccessControl, PendingRoleGrant, DEFAULT_ADMIN_ROLE};
 use crate::events::ResponseHandler;
+use crate::helpers::pagination::query_limit;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw_utils::Expiration;
 
 #[cw_serde]
 pub struct QueryHasRoleResponse {
@@ -12,9 +14,73 @@ pub struct QueryHasRoleResponse {
 pub struct QueryAdminRoleResponse {
     pub admin_role: String,
 }
-pub fn query_has_role(deps: Deps, role: String, addr: Addr) -> StdResult<QueryHasRoleResponse> {
+
+#[cw_serde]
+pub struct QueryRoleExpirationResponse {
+    pub expiration: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct QueryRoleInfoResponse {
+    pub role: String,
+    pub admin_role: String,
+    pub description: Option<String>,
+    pub created_at: Option<u64>,
+    pub member_count: u32,
+}
+
+#[cw_serde]
+pub struct QueryRoleMemberCountResponse {
+    pub count: u32,
+    // Members without expiration, only they count towards min_holders
+    pub permanent_count: u32,
+    pub min_holders: u32,
+}
+
+#[cw_serde]
+pub struct PendingRoleGrantEntry {
+    pub addr: Addr,
+    pub pending_grant: PendingRoleGrant,
+}
+
+#[cw_serde]
+pub struct QueryPendingRoleGrantsResponse {
+    pub pending_grants: Vec<PendingRoleGrantEntry>,
+}
+
+#[cw_serde]
+pub struct QueryRoleMembersResponse {
+    pub members: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct QueryRolesOfResponse {
+    pub roles: Vec<String>,
+}
+
+#[cw_serde]
+pub struct QueryRolesResponse {
+    pub roles: Vec<String>,
+}
+
+pub fn query_has_role(
+    deps: Deps,
+    env: Env,
+    role: String,
+    addr: Addr,
+) -> StdResult<QueryHasRoleResponse> {
     Ok(QueryHasRoleResponse {
-        has_role: AccessControl::has_role(deps.storage, &role, &addr),
+        has_role: AccessControl::has_role(deps.storage, &env, &role, &addr),
+    })
+}
+
+pub fn query_role_expiration(
+    deps: Deps,
+    role: String,
+    addr: Addr,
+) -> StdResult<QueryRoleExpirationResponse> {
+    Ok(QueryRoleExpirationResponse {
+        expiration: AccessControl::role_expiration(deps.storage, &role, &addr)?,
     })
 }
 
@@ -24,9 +90,144 @@ pub fn query_admin_role(deps: Deps, role: String) -> StdResult<QueryAdminRoleRes
     })
 }
 
+pub fn query_roles_of(
+    deps: Deps,
+    addr: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QueryRolesOfResponse> {
+    let roles = AccessControl::range_roles_of(deps.storage, &addr, start_after.as_deref())
+        .take(query_limit(limit))
+        .collect::<StdResult<Vec<String>>>()?;
+
+    Ok(QueryRolesOfResponse { roles })
+}
+
+pub fn query_role_members(
+    deps: Deps,
+    role: String,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<QueryRoleMembersResponse> {
+    let members =
+        AccessControl::range_addresses_with_role(deps.storage, &role, start_after.as_ref())?
+            .take(query_limit(limit))
+            .collect::<StdResult<Vec<Addr>>>()?;
+
+    Ok(QueryRoleMembersResponse { members })
+}
+
+pub fn query_role_info(deps: Deps, role: String) -> StdResult<QueryRoleInfoResponse> {
+    let role_data = AccessControl::role_info(deps.storage, &role)?;
+    let member_count = AccessControl::role_member_count(deps.storage, &role)?;
+
+    Ok(QueryRoleInfoResponse {
+        role,
+        admin_role: role_data.admin_role,
+        description: role_data.description,
+        created_at: role_data.created_at,
+        member_count,
+    })
+}
+
+pub fn query_role_member_count(
+    deps: Deps,
+    role: String,
+) -> StdResult<QueryRoleMemberCountResponse> {
+    Ok(QueryRoleMemberCountResponse {
+        count: AccessControl::role_member_count(deps.storage, &role)?,
+        permanent_count: AccessControl::permanent_role_member_count(deps.storage, &role)?,
+        min_holders: AccessControl::min_role_holders(deps.storage, &role)?,
+    })
+}
+
+pub fn query_pending_role_grants(
+    deps: Deps,
+    role: String,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<QueryPendingRoleGrantsResponse> {
+    let pending_grants =
+        AccessControl::range_pending_role_grants(deps.storage, &role, start_after.as_ref())
+            .take(query_limit(limit))
+            .map(|res| {
+                res.map(|(addr, pending_grant)| PendingRoleGrantEntry {
+                    addr,
+                    pending_grant,
+                })
+            })
+            .collect::<StdResult<Vec<PendingRoleGrantEntry>>>()?;
+
+    Ok(QueryPendingRoleGrantsResponse { pending_grants })
+}
+
+pub fn query_roles(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QueryRolesResponse> {
+    let roles = AccessControl::range_roles(deps.storage, start_after.as_deref())
+        .take(query_limit(limit))
+        .collect::<StdResult<Vec<String>>>()?;
+
+    Ok(QueryRolesResponse { roles })
+}
+
+pub fn execute_create_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    admin_role: Option<String>,
+    description: Option<String>,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+    let admin_role = admin_role.unwrap_or_else(|| DEFAULT_ADMIN_ROLE.to_string());
+
+    AccessControl::create_role(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &role,
+        &admin_role,
+        description,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "create_role")
+        .add_attribute("sender", info.sender)
+        .add_attribute("role", role)
+        .add_attribute("admin_role", admin_role))
+}
+
+pub fn execute_delete_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    AccessControl::delete_role(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &role,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "delete_role")
+        .add_attribute("sender", info.sender)
+        .add_attribute("role", role))
+}
+
 pub fn execute_grant_role(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     role: String,
     grant_to_address: Addr,
@@ -35,6 +236,7 @@ pub fn execute_grant_role(
 
     AccessControl::grant_role(
         deps.storage,
+        &env,
         &mut response_handler,
         &info.sender,
         &role,
@@ -49,9 +251,218 @@ pub fn execute_grant_role(
         .add_attribute("addr", grant_to_address.to_string()))
 }
 
+pub fn execute_grant_role_until(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    grant_to_address: Addr,
+    expiration: Expiration,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    AccessControl::grant_role_until(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &role,
+        &grant_to_address,
+        expiration,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "grant_role_until")
+        .add_attribute("sender", info.sender)
+        .add_attribute("role", role)
+        .add_attribute("addr", grant_to_address.to_string())
+        .add_attribute("expiration", expiration.to_string()))
+}
+
+pub fn execute_batch_grant_roles(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    grants: Vec<(String, Addr)>,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+    let count = grants.len();
+
+    AccessControl::grant_roles(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        grants,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "batch_grant_roles")
+        .add_attribute("sender", info.sender)
+        .add_attribute("count", count.to_string()))
+}
+
+pub fn execute_batch_revoke_roles(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    revocations: Vec<(String, Addr)>,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+    let count = revocations.len();
+
+    AccessControl::revoke_roles(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        revocations,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "batch_revoke_roles")
+        .add_attribute("sender", info.sender)
+        .add_attribute("count", count.to_string()))
+}
+
+pub fn execute_change_admin_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    new_admin_role: String,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    AccessControl::change_admin_role(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &role,
+        &new_admin_role,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "change_admin_role")
+        .add_attribute("sender", info.sender)
+        .add_attribute("role", role)
+        .add_attribute("new_admin_role", new_admin_role))
+}
+
+pub fn execute_set_min_role_holders(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    min_holders: u32,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    AccessControl::set_min_role_holders(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &role,
+        min_holders,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "set_min_role_holders")
+        .add_attribute("sender", info.sender)
+        .add_attribute("role", role)
+        .add_attribute("min_holders", min_holders.to_string()))
+}
+
+pub fn execute_propose_role_grant(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    grant_to_address: Addr,
+    expiration: Option<Expiration>,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+    let expiration = expiration.unwrap_or(Expiration::Never {});
+
+    AccessControl::propose_role_grant(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &role,
+        &grant_to_address,
+        expiration,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "propose_role_grant")
+        .add_attribute("sender", info.sender)
+        .add_attribute("role", role)
+        .add_attribute("addr", grant_to_address.to_string())
+        .add_attribute("expiration", expiration.to_string()))
+}
+
+pub fn execute_accept_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    AccessControl::accept_role(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &role,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "accept_role")
+        .add_attribute("sender", &info.sender)
+        .add_attribute("role", role)
+        .add_attribute("addr", info.sender))
+}
+
+pub fn execute_cancel_pending_role_grant(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    grant_to_address: Addr,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    AccessControl::cancel_pending_role_grant(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &role,
+        &grant_to_address,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "cancel_pending_role_grant")
+        .add_attribute("sender", info.sender)
+        .add_attribute("role", role)
+        .add_attribute("addr", grant_to_address.to_string()))
+}
+
 pub fn execute_revoke_role(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     role: String,
     address_to_revoke: Addr,
@@ -60,6 +471,7 @@ pub fn execute_revoke_role(
 
     AccessControl::revoke_role(
         deps.storage,
+        &env,
         &mut response_handler,
         &info.sender,
         &role,
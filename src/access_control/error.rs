@@ -1,4 +1,5 @@
 use cosmwasm_std::{Addr, StdError};
+use cw_utils::Expiration;
 
 pub fn no_role_error(address: &Addr, role: Option<&str>) -> StdError {
     let role_str = role.map(|role| format!(" '{}'", role)).unwrap_or_default();
@@ -11,3 +12,72 @@ pub fn no_role_error(address: &Addr, role: Option<&str>) -> StdError {
 pub fn sender_is_not_role_admin_error(role: &str) -> StdError {
     StdError::generic_err(format!("Sender is not admin of the '{}' role", role))
 }
+
+pub fn expiration_in_past_error(expiration: &Expiration) -> StdError {
+    StdError::generic_err(format!("Role grant {} is already expired", expiration))
+}
+
+pub fn no_pending_role_grant_error(role: &str, address: &Addr) -> StdError {
+    StdError::generic_err(format!(
+        "No pending grant of the '{}' role for address {}",
+        role, address
+    ))
+}
+
+pub fn pending_role_grant_expired_error(role: &str, address: &Addr) -> StdError {
+    StdError::generic_err(format!(
+        "Pending grant of the '{}' role for address {} is expired",
+        role, address
+    ))
+}
+
+pub fn two_step_grant_required_error(role: &str) -> StdError {
+    StdError::generic_err(format!(
+        "Role '{}' can only be granted to new holders through propose and accept",
+        role
+    ))
+}
+
+pub fn min_role_holders_error(role: &str, min_holders: u32) -> StdError {
+    StdError::generic_err(format!(
+        "Role '{}' must keep at least {} holder(s)",
+        role, min_holders
+    ))
+}
+
+pub fn role_batch_too_large_error(max_batch_size: u32) -> StdError {
+    StdError::generic_err(format!(
+        "Batch exceeds the maximum of {} role changes",
+        max_batch_size
+    ))
+}
+
+pub fn unknown_role_error(role: &str) -> StdError {
+    StdError::generic_err(format!("Unknown role '{}'", role))
+}
+
+pub fn role_already_exists_error(role: &str) -> StdError {
+    StdError::generic_err(format!("Role '{}' already exists", role))
+}
+
+pub fn role_not_found_error(role: &str) -> StdError {
+    StdError::generic_err(format!("Role '{}' was not created", role))
+}
+
+pub fn role_not_empty_error(role: &str) -> StdError {
+    StdError::generic_err(format!("Role '{}' still has members", role))
+}
+
+pub fn role_has_pending_grants_error(role: &str) -> StdError {
+    StdError::generic_err(format!(
+        "Role '{}' still has pending grants, they have to be cancelled first",
+        role
+    ))
+}
+
+pub fn unsupported_schema_version_error(version: u32) -> StdError {
+    StdError::generic_err(format!(
+        "Unsupported access control schema version {}",
+        version
+    ))
+}
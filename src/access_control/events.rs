@@ -1,4 +1,6 @@
+use crate::events::helpers::option_to_string;
 use crate::events::IntoEvent;
+use cw_utils::Expiration;
 
 pub struct AccessControlRoleUpdatedEvent<'a> {
     pub role: &'a str,
@@ -18,6 +20,29 @@ impl IntoEvent for AccessControlRoleUpdatedEvent<'_> {
     }
 }
 
+pub struct AccessControlRoleCreatedEvent<'a> {
+    pub role: &'a str,
+    pub admin_role: &'a str,
+    pub description: Option<&'a str>,
+}
+
+impl IntoEvent for AccessControlRoleCreatedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "access_control_role_created"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("role".to_string(), self.role.to_string()),
+            ("admin_role".to_string(), self.admin_role.to_string()),
+            (
+                "description".to_string(),
+                option_to_string(self.description),
+            ),
+        ]
+    }
+}
+
 pub struct AccessControlRoleRemovedEvent<'a> {
     pub role: &'a str,
 }
@@ -35,6 +60,7 @@ impl IntoEvent for AccessControlRoleRemovedEvent<'_> {
 pub struct AccessControlHasRoleUpdatedEvent<'a> {
     pub role: &'a str,
     pub addr: &'a str,
+    pub expiration: &'a Expiration,
 }
 
 impl IntoEvent for AccessControlHasRoleUpdatedEvent<'_> {
@@ -46,6 +72,7 @@ impl IntoEvent for AccessControlHasRoleUpdatedEvent<'_> {
         vec![
             ("role".to_string(), self.role.to_string()),
             ("addr".to_string(), self.addr.to_string()),
+            ("expiration".to_string(), self.expiration.to_string()),
         ]
     }
 }
@@ -67,3 +94,79 @@ impl IntoEvent for AccessControlHasRoleRemovedEvent<'_> {
         ]
     }
 }
+
+pub struct AccessControlMinRoleHoldersUpdatedEvent<'a> {
+    pub role: &'a str,
+    pub min_holders: &'a u32,
+}
+
+impl IntoEvent for AccessControlMinRoleHoldersUpdatedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "access_control_min_role_holders_updated"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("role".to_string(), self.role.to_string()),
+            ("min_holders".to_string(), self.min_holders.to_string()),
+        ]
+    }
+}
+
+pub struct AccessControlRoleGrantProposedEvent<'a> {
+    pub role: &'a str,
+    pub addr: &'a str,
+    pub proposed_by: &'a str,
+    pub expiration: &'a Expiration,
+}
+
+impl IntoEvent for AccessControlRoleGrantProposedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "access_control_role_grant_proposed"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("role".to_string(), self.role.to_string()),
+            ("addr".to_string(), self.addr.to_string()),
+            ("proposed_by".to_string(), self.proposed_by.to_string()),
+            ("expiration".to_string(), self.expiration.to_string()),
+        ]
+    }
+}
+
+pub struct AccessControlRoleGrantAcceptedEvent<'a> {
+    pub role: &'a str,
+    pub addr: &'a str,
+}
+
+impl IntoEvent for AccessControlRoleGrantAcceptedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "access_control_role_grant_accepted"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("role".to_string(), self.role.to_string()),
+            ("addr".to_string(), self.addr.to_string()),
+        ]
+    }
+}
+
+pub struct AccessControlRoleGrantCancelledEvent<'a> {
+    pub role: &'a str,
+    pub addr: &'a str,
+}
+
+impl IntoEvent for AccessControlRoleGrantCancelledEvent<'_> {
+    fn event_name(&self) -> &str {
+        "access_control_role_grant_cancelled"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("role".to_string(), self.role.to_string()),
+            ("addr".to_string(), self.addr.to_string()),
+        ]
+    }
+}
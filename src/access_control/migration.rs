@@ -0,0 +1,269 @@
+use crate::access_control::error::unsupported_schema_version_error;
+use crate::access_control::storage::AccessControlStorage;
+use crate::helpers::iteration_guard::{IterationGuard, IterationResult};
+use crate::unwrap_or_stop;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::Item;
+
+// 1: role data with admin role only, no reverse index and member counts
+// 2: role data with description and creation height, reverse index and member counts
+pub const ACCESS_CONTROL_SCHEMA_VERSION: u32 = 2;
+
+const SCHEMA_VERSION: Item<u32> = Item::new("access_control_schema_version");
+const MIGRATION_CURSOR: Item<MigrationCursor> = Item::new("access_control_migration_cursor");
+
+// Number of keys loaded from storage at once
+const TAKE: usize = 10;
+
+#[cw_serde]
+#[derive(Default)]
+struct MigrationCursor {
+    role_data_done: bool,
+    last_role: Option<String>,
+    last_has_role: Option<(String, Addr)>,
+}
+
+pub fn access_control_schema_version(storage: &dyn Storage) -> StdResult<Option<u32>> {
+    SCHEMA_VERSION.may_load(storage)
+}
+
+// Member counts are scanned from grants until the stored version is current
+pub fn is_access_control_migrated(storage: &dyn Storage) -> StdResult<bool> {
+    Ok(SCHEMA_VERSION.may_load(storage)? == Some(ACCESS_CONTROL_SCHEMA_VERSION))
+}
+
+// To be called at instantiate, new contracts don't need any migration
+pub fn init_access_control_schema_version(storage: &mut dyn Storage) -> StdResult<()> {
+    SCHEMA_VERSION.save(storage, &ACCESS_CONTROL_SCHEMA_VERSION)
+}
+
+// Migrates access control storage to the current schema version.
+// `from_version` is used only if no schema version is stored yet, contracts deployed before
+// versioning was introduced should pass 1. Passing the current version just stores it, same as
+// init_access_control_schema_version. Returns Stopped if max_iterations was reached,
+// the call has to be repeated (e.g. in a separate transaction) until it returns Done.
+pub fn migrate_access_control(
+    storage: &mut dyn Storage,
+    from_version: u32,
+    max_iterations: u32,
+) -> StdResult<IterationResult<()>> {
+    let version = SCHEMA_VERSION.may_load(storage)?.unwrap_or(from_version);
+    let mut iteration_guard = IterationGuard::new(max_iterations);
+
+    match version {
+        ACCESS_CONTROL_SCHEMA_VERSION => {}
+        1 => {
+            unwrap_or_stop!(migrate_v1_to_v2(storage, &mut iteration_guard)?);
+        }
+        _ => return Err(unsupported_schema_version_error(version)),
+    }
+
+    MIGRATION_CURSOR.remove(storage);
+    SCHEMA_VERSION.save(storage, &ACCESS_CONTROL_SCHEMA_VERSION)?;
+    Ok(IterationResult::Done(()))
+}
+
+fn migrate_v1_to_v2(
+    storage: &mut dyn Storage,
+    iteration_guard: &mut IterationGuard,
+) -> StdResult<IterationResult<()>> {
+    let mut cursor = MIGRATION_CURSOR.may_load(storage)?.unwrap_or_default();
+
+    // Rewrite role data to the new layout
+    while !cursor.role_data_done {
+        let roles = AccessControlStorage::range_roles(storage, cursor.last_role.as_deref())
+            .take(TAKE)
+            .collect::<StdResult<Vec<String>>>()?;
+        cursor.role_data_done = roles.len() < TAKE;
+
+        for role in roles {
+            if iteration_guard.next_iteration().is_stopped() {
+                cursor.role_data_done = false;
+                MIGRATION_CURSOR.save(storage, &cursor)?;
+                return Ok(IterationResult::Stopped);
+            }
+
+            AccessControlStorage::rewrite_role_data(storage, &role)?;
+            cursor.last_role = Some(role);
+        }
+    }
+
+    // Build reverse index and member counts from existing grants
+    loop {
+        let grants = AccessControlStorage::range_all_has_role(
+            storage,
+            cursor
+                .last_has_role
+                .as_ref()
+                .map(|(role, addr)| (role.as_str(), addr)),
+        )
+        .take(TAKE)
+        .collect::<StdResult<Vec<(String, Addr)>>>()?;
+        let is_last_batch = grants.len() < TAKE;
+
+        for (role, addr) in grants {
+            if iteration_guard.next_iteration().is_stopped() {
+                MIGRATION_CURSOR.save(storage, &cursor)?;
+                return Ok(IterationResult::Stopped);
+            }
+
+            AccessControlStorage::index_role_member(storage, &role, &addr)?;
+            cursor.last_has_role = Some((role, addr));
+        }
+
+        if is_last_batch {
+            return Ok(IterationResult::Done(()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::{AccessControl, RoleData, DEFAULT_ADMIN_ROLE};
+    use crate::testing::helpers::assert_err;
+    use cosmwasm_std::testing::mock_dependencies;
+    use cw_storage_plus::Map;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct RoleDataV1 {
+        admin_role: String,
+    }
+
+    const ROLE_V1: Map<&str, RoleDataV1> = Map::new("roles");
+    const HAS_ROLE_V1: Map<(&str, &Addr), ()> = Map::new("has_role");
+
+    #[test]
+    fn test_migrate_v1_to_v2() {
+        let mut deps = mock_dependencies();
+
+        // Legacy storage layout
+        for i in 0..15 {
+            assert!(ROLE_V1
+                .save(
+                    deps.as_mut().storage,
+                    &format!("role_{:02}", i),
+                    &RoleDataV1 {
+                        admin_role: "other_admin".to_string(),
+                    },
+                )
+                .is_ok());
+        }
+        for i in 0..25 {
+            let role = if i % 2 == 0 {
+                DEFAULT_ADMIN_ROLE
+            } else {
+                "role_00"
+            };
+            assert!(HAS_ROLE_V1
+                .save(
+                    deps.as_mut().storage,
+                    (role, &Addr::unchecked(format!("user_{:02}", i))),
+                    &(),
+                )
+                .is_ok());
+        }
+
+        assert_err(
+            &migrate_access_control(deps.as_mut().storage, 0, 10),
+            &unsupported_schema_version_error(0),
+        );
+
+        // Counts are scanned from grants before the migration
+        assert!(!is_access_control_migrated(deps.as_ref().storage).unwrap());
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            13
+        );
+        assert_eq!(
+            AccessControl::permanent_role_member_count(deps.as_ref().storage, "role_00").unwrap(),
+            12
+        );
+
+        let mut n_calls = 0;
+        loop {
+            n_calls += 1;
+            if migrate_access_control(deps.as_mut().storage, 1, 10)
+                .unwrap()
+                .is_done()
+            {
+                break;
+            }
+        }
+        // 40 entries with 9 iterations per call
+        assert_eq!(n_calls, 5);
+
+        assert_eq!(
+            access_control_schema_version(deps.as_ref().storage).unwrap(),
+            Some(ACCESS_CONTROL_SCHEMA_VERSION)
+        );
+        assert_eq!(
+            AccessControl::role_info(deps.as_ref().storage, "role_14").unwrap(),
+            RoleData {
+                admin_role: "other_admin".to_string(),
+                description: None,
+                created_at: None,
+            }
+        );
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            13
+        );
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, "role_00").unwrap(),
+            12
+        );
+        assert_eq!(
+            AccessControl::range_roles_of(deps.as_ref().storage, &Addr::unchecked("user_01"), None)
+                .collect::<StdResult<Vec<String>>>()
+                .unwrap(),
+            vec!["role_00".to_string()]
+        );
+
+        // Migration of the current version is no-op
+        assert!(migrate_access_control(deps.as_mut().storage, 1, 10)
+            .unwrap()
+            .is_done());
+        assert_eq!(
+            AccessControl::role_member_count(deps.as_ref().storage, DEFAULT_ADMIN_ROLE).unwrap(),
+            13
+        );
+        assert_eq!(
+            AccessControl::permanent_role_member_count(deps.as_ref().storage, "role_00").unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_migrate_fresh_deploy() {
+        let mut deps = mock_dependencies();
+
+        assert_eq!(
+            access_control_schema_version(deps.as_ref().storage).unwrap(),
+            None
+        );
+
+        // Current version is stored without any migration
+        assert!(
+            migrate_access_control(deps.as_mut().storage, ACCESS_CONTROL_SCHEMA_VERSION, 10)
+                .unwrap()
+                .is_done()
+        );
+        assert_eq!(
+            access_control_schema_version(deps.as_ref().storage).unwrap(),
+            Some(ACCESS_CONTROL_SCHEMA_VERSION)
+        );
+        assert!(is_access_control_migrated(deps.as_ref().storage).unwrap());
+
+        // Stored version takes precedence over from_version
+        assert!(migrate_access_control(deps.as_mut().storage, 1, 10)
+            .unwrap()
+            .is_done());
+        assert_eq!(
+            access_control_schema_version(deps.as_ref().storage).unwrap(),
+            Some(ACCESS_CONTROL_SCHEMA_VERSION)
+        );
+    }
+}
@@ -2,12 +2,16 @@ mod access_control_impl;
 mod contract;
 pub mod error;
 mod events;
+mod migration;
 mod msg;
 mod role;
 mod storage;
+mod typed;
 
 pub use access_control_impl::*;
 pub use contract::*;
 pub use events::*;
+pub use migration::*;
 pub use msg::*;
 pub use role::*;
+pub use typed::*;
@@ -1,34 +1,172 @@
 use crate::access_control::{
-    execute_grant_role, execute_renounce_role, execute_revoke_role, query_admin_role,
-    query_has_role,
+    ensure_known_role, execute_accept_role, execute_batch_grant_roles, execute_batch_revoke_roles,
+    execute_cancel_pending_role_grant, execute_change_admin_role, execute_create_role,
+    execute_delete_role, execute_grant_role, execute_grant_role_until, execute_propose_role_grant,
+    execute_renounce_role, execute_revoke_role, execute_set_min_role_holders, query_admin_role,
+    query_has_role, query_pending_role_grants, query_role_expiration, query_role_info,
+    query_role_member_count, query_role_members, query_roles, query_roles_of, Role,
+};
+use crate::access_control::{
+    QueryAdminRoleResponse, QueryHasRoleResponse, QueryPendingRoleGrantsResponse,
+    QueryRoleExpirationResponse, QueryRoleInfoResponse, QueryRoleMemberCountResponse,
+    QueryRoleMembersResponse, QueryRolesOfResponse, QueryRolesResponse,
 };
-use crate::access_control::{QueryAdminRoleResponse, QueryHasRoleResponse};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
     to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
 };
+use cw_utils::Expiration;
 
 #[cw_serde]
 pub enum AccessControlExecuteMsg {
-    GrantRole { role: String, addr: Addr },
+    CreateRole {
+        role: String,
+        admin_role: Option<String>,
+        description: Option<String>,
+    },
+
+    DeleteRole {
+        role: String,
+    },
+
+    GrantRole {
+        role: String,
+        addr: Addr,
+    },
+
+    GrantRoleUntil {
+        role: String,
+        addr: Addr,
+        expiration: Expiration,
+    },
+
+    // Proposal never expires if expiration is not set
+    ProposeRoleGrant {
+        role: String,
+        addr: Addr,
+        expiration: Option<Expiration>,
+    },
+
+    AcceptRole {
+        role: String,
+    },
 
-    RevokeRole { role: String, addr: Addr },
+    CancelPendingRoleGrant {
+        role: String,
+        addr: Addr,
+    },
 
-    RenounceRole { role: String },
+    RevokeRole {
+        role: String,
+        addr: Addr,
+    },
+
+    BatchGrant {
+        grants: Vec<(String, Addr)>,
+    },
+
+    BatchRevoke {
+        revocations: Vec<(String, Addr)>,
+    },
+
+    ChangeAdminRole {
+        role: String,
+        new_admin_role: String,
+    },
+
+    SetMinRoleHolders {
+        role: String,
+        min_holders: u32,
+    },
+
+    RenounceRole {
+        role: String,
+    },
 }
-pub fn handle_access_control_execute_msg(
+
+impl AccessControlExecuteMsg {
+    // All role identifiers referenced by the message
+    pub fn roles(&self) -> Vec<&str> {
+        match self {
+            AccessControlExecuteMsg::CreateRole {
+                role, admin_role, ..
+            } => [Some(role), admin_role.as_ref()]
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .collect(),
+            AccessControlExecuteMsg::DeleteRole { role }
+            | AccessControlExecuteMsg::GrantRole { role, .. }
+            | AccessControlExecuteMsg::GrantRoleUntil { role, .. }
+            | AccessControlExecuteMsg::ProposeRoleGrant { role, .. }
+            | AccessControlExecuteMsg::AcceptRole { role }
+            | AccessControlExecuteMsg::CancelPendingRoleGrant { role, .. }
+            | AccessControlExecuteMsg::RevokeRole { role, .. }
+            | AccessControlExecuteMsg::SetMinRoleHolders { role, .. }
+            | AccessControlExecuteMsg::RenounceRole { role } => vec![role],
+            AccessControlExecuteMsg::BatchGrant { grants: changes }
+            | AccessControlExecuteMsg::BatchRevoke {
+                revocations: changes,
+            } => changes.iter().map(|(role, _)| role.as_str()).collect(),
+            AccessControlExecuteMsg::ChangeAdminRole {
+                role,
+                new_admin_role,
+            } => vec![role, new_admin_role],
+        }
+    }
+}
+
+// Rejects messages referencing roles which are not declared by R
+pub fn handle_access_control_execute_msg<R: Role>(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: AccessControlExecuteMsg,
 ) -> StdResult<Response> {
+    for role in msg.roles() {
+        ensure_known_role::<R>(role)?;
+    }
+
     match msg {
+        AccessControlExecuteMsg::CreateRole {
+            role,
+            admin_role,
+            description,
+        } => execute_create_role(deps, env, info, role, admin_role, description),
+        AccessControlExecuteMsg::DeleteRole { role } => execute_delete_role(deps, env, info, role),
         AccessControlExecuteMsg::GrantRole { role, addr } => {
             execute_grant_role(deps, env, info, role, addr)
         }
+        AccessControlExecuteMsg::GrantRoleUntil {
+            role,
+            addr,
+            expiration,
+        } => execute_grant_role_until(deps, env, info, role, addr, expiration),
+        AccessControlExecuteMsg::ProposeRoleGrant {
+            role,
+            addr,
+            expiration,
+        } => execute_propose_role_grant(deps, env, info, role, addr, expiration),
+        AccessControlExecuteMsg::AcceptRole { role } => execute_accept_role(deps, env, info, role),
+        AccessControlExecuteMsg::CancelPendingRoleGrant { role, addr } => {
+            execute_cancel_pending_role_grant(deps, env, info, role, addr)
+        }
         AccessControlExecuteMsg::RevokeRole { role, addr } => {
             execute_revoke_role(deps, env, info, role, addr)
         }
+        AccessControlExecuteMsg::BatchGrant { grants } => {
+            execute_batch_grant_roles(deps, env, info, grants)
+        }
+        AccessControlExecuteMsg::BatchRevoke { revocations } => {
+            execute_batch_revoke_roles(deps, env, info, revocations)
+        }
+        AccessControlExecuteMsg::ChangeAdminRole {
+            role,
+            new_admin_role,
+        } => execute_change_admin_role(deps, env, info, role, new_admin_role),
+        AccessControlExecuteMsg::SetMinRoleHolders { role, min_holders } => {
+            execute_set_min_role_holders(deps, env, info, role, min_holders)
+        }
         AccessControlExecuteMsg::RenounceRole { role } => {
             execute_renounce_role(deps, env, info, role)
         }
@@ -43,10 +181,46 @@ pub enum AccessControlQueryMsg {
 
     #[returns(QueryHasRoleResponse)]
     QueryHasRole { addr: Addr, role: String },
+
+    #[returns(QueryRoleExpirationResponse)]
+    QueryRoleExpiration { addr: Addr, role: String },
+
+    #[returns(QueryRoleInfoResponse)]
+    QueryRoleInfo { role: String },
+
+    #[returns(QueryRoleMemberCountResponse)]
+    QueryRoleMemberCount { role: String },
+
+    #[returns(QueryPendingRoleGrantsResponse)]
+    QueryPendingRoleGrants {
+        role: String,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryRoleMembersResponse)]
+    QueryRoleMembers {
+        role: String,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryRolesOfResponse)]
+    QueryRolesOf {
+        addr: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryRolesResponse)]
+    QueryRoles {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 pub fn handle_access_control_query_msg(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: AccessControlQueryMsg,
 ) -> StdResult<Binary> {
     match msg {
@@ -54,7 +228,121 @@ pub fn handle_access_control_query_msg(
             to_json_binary(&query_admin_role(deps, role)?)
         }
         AccessControlQueryMsg::QueryHasRole { addr, role } => {
-            to_json_binary(&query_has_role(deps, role, addr)?)
+            to_json_binary(&query_has_role(deps, env, role, addr)?)
+        }
+        AccessControlQueryMsg::QueryRoleExpiration { addr, role } => {
+            to_json_binary(&query_role_expiration(deps, role, addr)?)
         }
+        AccessControlQueryMsg::QueryRoleInfo { role } => {
+            to_json_binary(&query_role_info(deps, role)?)
+        }
+        AccessControlQueryMsg::QueryRoleMemberCount { role } => {
+            to_json_binary(&query_role_member_count(deps, role)?)
+        }
+        AccessControlQueryMsg::QueryPendingRoleGrants {
+            role,
+            start_after,
+            limit,
+        } => to_json_binary(&query_pending_role_grants(deps, role, start_after, limit)?),
+        AccessControlQueryMsg::QueryRoleMembers {
+            role,
+            start_after,
+            limit,
+        } => to_json_binary(&query_role_members(deps, role, start_after, limit)?),
+        AccessControlQueryMsg::QueryRolesOf {
+            addr,
+            start_after,
+            limit,
+        } => to_json_binary(&query_roles_of(deps, addr, start_after, limit)?),
+        AccessControlQueryMsg::QueryRoles { start_after, limit } => {
+            to_json_binary(&query_roles(deps, start_after, limit)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::error::sender_is_not_role_admin_error;
+    use crate::access_control::{AccessControl, DEFAULT_ADMIN_ROLE};
+    use crate::define_roles;
+    use crate::events::ResponseHandler;
+    use crate::testing::helpers::{assert_err, deps_with_creator};
+    use cosmwasm_std::from_json;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    const MINTER_ROLE: &str = "minter";
+    const MINTER_ADMIN_ROLE: &str = "minter_admin";
+
+    define_roles! {
+        enum TestRole {
+            Admin => DEFAULT_ADMIN_ROLE,
+            Minter => MINTER_ROLE,
+            MinterAdmin => MINTER_ADMIN_ROLE,
+        }
+    }
+
+    #[test]
+    fn test_change_admin_role_msg() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEFAULT_ADMIN_ROLE,
+            &creator
+        )
+        .is_ok());
+
+        let msg = AccessControlExecuteMsg::ChangeAdminRole {
+            role: MINTER_ROLE.to_string(),
+            new_admin_role: MINTER_ADMIN_ROLE.to_string(),
+        };
+
+        assert_err(
+            &handle_access_control_execute_msg::<TestRole>(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(user.as_str(), &[]),
+                msg.clone(),
+            ),
+            &sender_is_not_role_admin_error(MINTER_ROLE),
+        );
+
+        assert!(handle_access_control_execute_msg::<TestRole>(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            msg.clone(),
+        )
+        .is_ok());
+
+        let res: QueryAdminRoleResponse = from_json(
+            handle_access_control_query_msg(
+                deps.as_ref(),
+                env.clone(),
+                AccessControlQueryMsg::QueryAdminRole {
+                    role: MINTER_ROLE.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res.admin_role, MINTER_ADMIN_ROLE);
+
+        // Previous admin no longer administers the role
+        assert_err(
+            &handle_access_control_execute_msg::<TestRole>(
+                deps.as_mut(),
+                env,
+                mock_info(creator.as_str(), &[]),
+                msg,
+            ),
+            &sender_is_not_role_admin_error(MINTER_ROLE),
+        );
     }
 }
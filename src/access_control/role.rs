@@ -1,16 +1,76 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_utils::Expiration;
 
 pub const DEFAULT_ADMIN_ROLE: &str = "admin";
 
+// Roles declared by a contract, see `define_roles!`
+pub trait Role: Copy + 'static {
+    fn as_str(&self) -> &'static str;
+
+    // Registry of all valid roles
+    fn all() -> &'static [Self];
+
+    fn from_role_str(role: &str) -> Option<Self> {
+        Self::all().iter().find(|r| r.as_str() == role).copied()
+    }
+}
+
+#[macro_export]
+macro_rules! define_roles {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident => $role:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        $vis enum $name {
+            $($variant),+
+        }
+
+        impl $crate::access_control::Role for $name {
+            fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $role),+
+                }
+            }
+
+            fn all() -> &'static [Self] {
+                &[$(Self::$variant),+]
+            }
+        }
+    };
+}
+
 #[cw_serde]
 pub struct RoleData {
     pub admin_role: String,
+    pub description: Option<String>,
+    // Block height of explicit role creation, None for implicitly used roles
+    pub created_at: Option<u64>,
 }
 
 impl Default for RoleData {
     fn default() -> Self {
         RoleData {
             admin_role: DEFAULT_ADMIN_ROLE.to_string(),
+            description: None,
+            created_at: None,
         }
     }
 }
+
+impl RoleData {
+    pub fn is_created(&self) -> bool {
+        self.created_at.is_some()
+    }
+}
+
+#[cw_serde]
+pub struct PendingRoleGrant {
+    pub proposed_by: Addr,
+    // Grant can't be accepted once expired
+    pub expiration: Expiration,
+}
@@ -1,14 +1,28 @@
-use crate::access_control::role::RoleData;
+use crate::access_control::migration::is_access_control_migrated;
+use crate::access_control::role::{PendingRoleGrant, RoleData};
 use crate::access_control::{
     AccessControlHasRoleRemovedEvent, AccessControlHasRoleUpdatedEvent,
-    AccessControlRoleRemovedEvent, AccessControlRoleUpdatedEvent, DEFAULT_ADMIN_ROLE,
+    AccessControlMinRoleHoldersUpdatedEvent, AccessControlRoleCreatedEvent,
+    AccessControlRoleGrantAcceptedEvent, AccessControlRoleGrantCancelledEvent,
+    AccessControlRoleGrantProposedEvent, AccessControlRoleRemovedEvent,
+    AccessControlRoleUpdatedEvent, DEFAULT_ADMIN_ROLE,
 };
 use crate::events::ResponseHandler;
-use cosmwasm_std::{Addr, Order, StdResult, Storage};
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, Env, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use cw_utils::Expiration;
 
 const ROLE: Map<&str, RoleData> = Map::new("roles");
 const HAS_ROLE: Map<(&str, &Addr), ()> = Map::new("has_role");
+// Reverse index of HAS_ROLE
+const ROLES_OF: Map<(&Addr, &str), ()> = Map::new("roles_of");
+// Only time-bound grants have an entry
+const ROLE_EXPIRATION: Map<(&str, &Addr), Expiration> = Map::new("role_expiration");
+const ROLE_MEMBER_COUNT: Map<&str, u32> = Map::new("role_member_count");
+// Members without expiration, only they count towards the minimum holders
+const ROLE_PERMANENT_MEMBER_COUNT: Map<&str, u32> = Map::new("role_permanent_member_count");
+const ROLE_MIN_HOLDERS: Map<&str, u32> = Map::new("role_min_holders");
+const PENDING_ROLE_GRANT: Map<(&str, &Addr), PendingRoleGrant> = Map::new("pending_role_grants");
 
 pub(crate) struct AccessControlStorage {}
 
@@ -27,13 +41,9 @@ impl AccessControlStorage {
     ) -> StdResult<()> {
         response_handler.add_event(AccessControlRoleUpdatedEvent { role, admin_role });
 
-        ROLE.save(
-            storage,
-            role,
-            &RoleData {
-                admin_role: admin_role.to_string(),
-            },
-        )
+        let mut role_data = ROLE.may_load(storage, role)?.unwrap_or_default();
+        role_data.admin_role = admin_role.to_string();
+        ROLE.save(storage, role, &role_data)
     }
 
     pub fn set_admin_role(
@@ -42,7 +52,10 @@ impl AccessControlStorage {
         role: &str,
         new_admin_role: &str,
     ) -> StdResult<()> {
-        if new_admin_role == DEFAULT_ADMIN_ROLE {
+        // Created roles keep their role data regardless of the admin role
+        let is_created = Self::get_role_data(storage, role)?.is_some_and(|data| data.is_created());
+
+        if new_admin_role == DEFAULT_ADMIN_ROLE && !is_created {
             Self::remove_role(storage, response_handler, role);
         } else {
             Self::update_role(storage, response_handler, role, new_admin_role)?;
@@ -51,32 +64,255 @@ impl AccessControlStorage {
         Ok(())
     }
 
+    pub fn create_role(
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        role: &str,
+        admin_role: &str,
+        description: Option<String>,
+        created_at: u64,
+    ) -> StdResult<()> {
+        response_handler.add_event(AccessControlRoleCreatedEvent {
+            role,
+            admin_role,
+            description: description.as_deref(),
+        });
+
+        ROLE.save(
+            storage,
+            role,
+            &RoleData {
+                admin_role: admin_role.to_string(),
+                description,
+                created_at: Some(created_at),
+            },
+        )
+    }
+
+    pub fn delete_role(
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        role: &str,
+    ) {
+        ROLE_MIN_HOLDERS.remove(storage, role);
+        Self::remove_role(storage, response_handler, role);
+    }
+
+    pub fn get_role_data(storage: &dyn Storage, role: &str) -> StdResult<Option<RoleData>> {
+        ROLE.may_load(storage, role)
+    }
+
     pub fn grant_role(
         storage: &mut dyn Storage,
         response_handler: &mut ResponseHandler,
         role: &str,
         grant_to_address: &Addr,
+        expiration: &Expiration,
     ) -> StdResult<()> {
         response_handler.add_event(AccessControlHasRoleUpdatedEvent {
             role,
             addr: grant_to_address.as_str(),
+            expiration,
         });
 
+        let is_indexed = ROLES_OF.has(storage, (grant_to_address, role));
+        let was_permanent = Self::is_permanent_member(storage, role, grant_to_address);
+        let is_permanent = matches!(expiration, Expiration::Never {});
+
+        if is_permanent {
+            ROLE_EXPIRATION.remove(storage, (role, grant_to_address));
+        } else {
+            ROLE_EXPIRATION.save(storage, (role, grant_to_address), expiration)?;
+        }
+
+        if !is_indexed {
+            Self::index_role_member(storage, role, grant_to_address)?;
+        } else if is_permanent && !was_permanent {
+            increase_count(storage, ROLE_PERMANENT_MEMBER_COUNT, role)?;
+        } else if !is_permanent && was_permanent {
+            decrease_count(storage, ROLE_PERMANENT_MEMBER_COUNT, role)?;
+        }
+
         HAS_ROLE.save(storage, (role, grant_to_address), &())
     }
 
+    // Member counts follow the reverse index, grants stored before the index existed
+    // are not counted until they are indexed by the migration
+    pub fn index_role_member(
+        storage: &mut dyn Storage,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<()> {
+        if ROLES_OF.has(storage, (address, role)) {
+            return Ok(());
+        }
+
+        increase_count(storage, ROLE_MEMBER_COUNT, role)?;
+        if !ROLE_EXPIRATION.has(storage, (role, address)) {
+            increase_count(storage, ROLE_PERMANENT_MEMBER_COUNT, role)?;
+        }
+        ROLES_OF.save(storage, (address, role), &())
+    }
+
     pub fn revoke_role(
         storage: &mut dyn Storage,
         response_handler: &mut ResponseHandler,
         role: &str,
         address_to_revoke: &Addr,
-    ) {
+    ) -> StdResult<()> {
         response_handler.add_event(AccessControlHasRoleRemovedEvent {
             role,
             addr: address_to_revoke.as_str(),
         });
 
-        HAS_ROLE.remove(storage, (role, address_to_revoke))
+        if ROLES_OF.has(storage, (address_to_revoke, role)) {
+            decrease_count(storage, ROLE_MEMBER_COUNT, role)?;
+            if !ROLE_EXPIRATION.has(storage, (role, address_to_revoke)) {
+                decrease_count(storage, ROLE_PERMANENT_MEMBER_COUNT, role)?;
+            }
+        }
+
+        ROLE_EXPIRATION.remove(storage, (role, address_to_revoke));
+        ROLES_OF.remove(storage, (address_to_revoke, role));
+        HAS_ROLE.remove(storage, (role, address_to_revoke));
+        Ok(())
+    }
+
+    // Grants stored before the counts existed are not counted until the migration is done,
+    // so the grants are scanned instead
+    pub fn get_member_count(storage: &dyn Storage, role: &str) -> StdResult<u32> {
+        if !is_access_control_migrated(storage)? {
+            return Self::scan_member_count(storage, role, false);
+        }
+
+        Ok(ROLE_MEMBER_COUNT
+            .may_load(storage, role)?
+            .unwrap_or_default())
+    }
+
+    pub fn get_permanent_member_count(storage: &dyn Storage, role: &str) -> StdResult<u32> {
+        if !is_access_control_migrated(storage)? {
+            return Self::scan_member_count(storage, role, true);
+        }
+
+        Ok(ROLE_PERMANENT_MEMBER_COUNT
+            .may_load(storage, role)?
+            .unwrap_or_default())
+    }
+
+    fn scan_member_count(
+        storage: &dyn Storage,
+        role: &str,
+        permanent_only: bool,
+    ) -> StdResult<u32> {
+        let mut count = 0;
+        for address in HAS_ROLE
+            .prefix(role)
+            .keys(storage, None, None, Order::Ascending)
+        {
+            if !permanent_only || !ROLE_EXPIRATION.has(storage, (role, &address?)) {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    // Role is held by a grant without expiration
+    pub fn is_permanent_member(storage: &dyn Storage, role: &str, address: &Addr) -> bool {
+        Self::has_role(storage, role, address) && !ROLE_EXPIRATION.has(storage, (role, address))
+    }
+
+    pub fn set_min_holders(
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        role: &str,
+        min_holders: u32,
+    ) -> StdResult<()> {
+        response_handler.add_event(AccessControlMinRoleHoldersUpdatedEvent {
+            role,
+            min_holders: &min_holders,
+        });
+
+        if min_holders == 0 {
+            ROLE_MIN_HOLDERS.remove(storage, role);
+            Ok(())
+        } else {
+            ROLE_MIN_HOLDERS.save(storage, role, &min_holders)
+        }
+    }
+
+    pub fn get_min_holders(storage: &dyn Storage, role: &str) -> StdResult<u32> {
+        Ok(ROLE_MIN_HOLDERS
+            .may_load(storage, role)?
+            .unwrap_or_default())
+    }
+
+    pub fn propose_role_grant(
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        role: &str,
+        grant_to_address: &Addr,
+        proposed_by: &Addr,
+        expiration: &Expiration,
+    ) -> StdResult<()> {
+        response_handler.add_event(AccessControlRoleGrantProposedEvent {
+            role,
+            addr: grant_to_address.as_str(),
+            proposed_by: proposed_by.as_str(),
+            expiration,
+        });
+
+        PENDING_ROLE_GRANT.save(
+            storage,
+            (role, grant_to_address),
+            &PendingRoleGrant {
+                proposed_by: proposed_by.clone(),
+                expiration: *expiration,
+            },
+        )
+    }
+
+    pub fn accept_pending_role_grant(
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<()> {
+        response_handler.add_event(AccessControlRoleGrantAcceptedEvent {
+            role,
+            addr: address.as_str(),
+        });
+
+        PENDING_ROLE_GRANT.remove(storage, (role, address));
+        Self::grant_role(
+            storage,
+            response_handler,
+            role,
+            address,
+            &Expiration::Never {},
+        )
+    }
+
+    pub fn cancel_pending_role_grant(
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        role: &str,
+        address: &Addr,
+    ) {
+        response_handler.add_event(AccessControlRoleGrantCancelledEvent {
+            role,
+            addr: address.as_str(),
+        });
+
+        PENDING_ROLE_GRANT.remove(storage, (role, address))
+    }
+
+    pub fn get_pending_role_grant(
+        storage: &dyn Storage,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<Option<PendingRoleGrant>> {
+        PENDING_ROLE_GRANT.may_load(storage, (role, address))
     }
 
     pub fn get_admin_role(storage: &dyn Storage, role: &str) -> StdResult<String> {
@@ -87,26 +323,155 @@ impl AccessControlStorage {
         HAS_ROLE.has(storage, (role, address))
     }
 
-    #[allow(dead_code)]
-    pub fn range_all_addresses_with_role<'a>(
+    // Reads grants directly, so grants not indexed by the migration are found as well
+    pub fn has_members(storage: &dyn Storage, role: &str) -> bool {
+        HAS_ROLE
+            .prefix(role)
+            .keys_raw(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+    }
+
+    pub fn has_pending_role_grants(storage: &dyn Storage, role: &str) -> bool {
+        PENDING_ROLE_GRANT
+            .prefix(role)
+            .keys_raw(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+    }
+
+    // Role is held and its grant is not expired
+    pub fn has_active_role(storage: &dyn Storage, env: &Env, role: &str, address: &Addr) -> bool {
+        if !Self::has_role(storage, role, address) {
+            return false;
+        }
+
+        // Unreadable expiration is treated as expired
+        match ROLE_EXPIRATION.may_load(storage, (role, address)) {
+            Ok(Some(expiration)) => !expiration.is_expired(&env.block),
+            Ok(None) => true,
+            Err(_) => false,
+        }
+    }
+
+    pub fn get_role_expiration(
+        storage: &dyn Storage,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<Option<Expiration>> {
+        if !Self::has_role(storage, role, address) {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            ROLE_EXPIRATION
+                .may_load(storage, (role, address))?
+                .unwrap_or(Expiration::Never {}),
+        ))
+    }
+
+    pub fn range_addresses_with_role<'a>(
         storage: &'a dyn Storage,
         role: &str,
+        start_after: Option<&Addr>,
     ) -> StdResult<Box<dyn Iterator<Item = StdResult<Addr>> + 'a>> {
         Ok(Box::new(
             HAS_ROLE
                 .prefix(role)
-                .range(storage, None, None, Order::Ascending)
+                .range(
+                    storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
                 .map(|res| res.map(|(addr, _)| addr)),
         ))
     }
 
-    #[allow(dead_code)]
-    pub fn range_all_roles<'a>(
+    pub fn range_all_has_role<'a>(
+        storage: &'a dyn Storage,
+        start_after: Option<(&str, &Addr)>,
+    ) -> Box<dyn Iterator<Item = StdResult<(String, Addr)>> + 'a> {
+        Box::new(HAS_ROLE.keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        ))
+    }
+
+    // Re-saves role data in the current layout
+    pub fn rewrite_role_data(storage: &mut dyn Storage, role: &str) -> StdResult<()> {
+        if let Some(role_data) = ROLE.may_load(storage, role)? {
+            ROLE.save(storage, role, &role_data)?;
+        }
+        Ok(())
+    }
+
+    pub fn range_pending_role_grants<'a>(
+        storage: &'a dyn Storage,
+        role: &str,
+        start_after: Option<&Addr>,
+    ) -> Box<dyn Iterator<Item = StdResult<(Addr, PendingRoleGrant)>> + 'a> {
+        Box::new(PENDING_ROLE_GRANT.prefix(role).range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        ))
+    }
+
+    pub fn range_roles_of<'a>(
         storage: &'a dyn Storage,
+        address: &Addr,
+        start_after: Option<&str>,
     ) -> Box<dyn Iterator<Item = StdResult<String>> + 'a> {
         Box::new(
-            ROLE.range(storage, None, None, Order::Ascending)
-                .map(|res| res.map(|(addr, _)| addr)),
+            ROLES_OF
+                .prefix(address)
+                .range(
+                    storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .map(|res| res.map(|(role, _)| role)),
+        )
+    }
+
+    pub fn range_roles<'a>(
+        storage: &'a dyn Storage,
+        start_after: Option<&str>,
+    ) -> Box<dyn Iterator<Item = StdResult<String>> + 'a> {
+        Box::new(
+            ROLE.range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .map(|res| res.map(|(role, _)| role)),
         )
     }
 }
+
+fn increase_count(storage: &mut dyn Storage, counts: Map<&str, u32>, role: &str) -> StdResult<()> {
+    counts.update(storage, role, |count| -> StdResult<u32> {
+        Ok(count.unwrap_or_default() + 1)
+    })?;
+    Ok(())
+}
+
+// Zero counts are removed
+fn decrease_count(storage: &mut dyn Storage, counts: Map<&str, u32>, role: &str) -> StdResult<()> {
+    let count = counts
+        .may_load(storage, role)?
+        .unwrap_or_default()
+        .saturating_sub(1);
+    if count == 0 {
+        counts.remove(storage, role);
+        Ok(())
+    } else {
+        counts.save(storage, role, &count)
+    }
+}
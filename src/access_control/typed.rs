@@ -0,0 +1,487 @@
+use crate::access_control::error::unknown_role_error;
+use crate::access_control::{AccessControl, PendingRoleGrant, Role, RoleData, DEFAULT_ADMIN_ROLE};
+use crate::events::ResponseHandler;
+use cosmwasm_std::{Addr, Deps, Env, StdResult, Storage};
+use cw_utils::Expiration;
+use std::marker::PhantomData;
+
+// Default admin role is always considered known
+pub fn ensure_known_role<R: Role>(role: &str) -> StdResult<()> {
+    if role == DEFAULT_ADMIN_ROLE || R::from_role_str(role).is_some() {
+        return Ok(());
+    }
+
+    Err(unknown_role_error(role))
+}
+
+// AccessControl restricted to roles declared by R
+pub struct TypedAccessControl<R: Role> {
+    role_type: PhantomData<R>,
+}
+
+impl<R: Role> TypedAccessControl<R> {
+    pub fn ensure_is_admin(
+        storage: &dyn Storage,
+        env: &Env,
+        sender: &Addr,
+        role: R,
+    ) -> StdResult<()> {
+        AccessControl::ensure_is_admin(storage, env, sender, role.as_str())
+    }
+
+    pub fn create_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+        admin_role: R,
+        description: Option<String>,
+    ) -> StdResult<()> {
+        AccessControl::create_role(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role.as_str(),
+            admin_role.as_str(),
+            description,
+        )
+    }
+
+    pub fn delete_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+    ) -> StdResult<()> {
+        AccessControl::delete_role(storage, env, response_handler, sender, role.as_str())
+    }
+
+    pub fn grant_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+        grant_to_address: &Addr,
+    ) -> StdResult<()> {
+        AccessControl::grant_role(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role.as_str(),
+            grant_to_address,
+        )
+    }
+
+    pub fn grant_role_until(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+        grant_to_address: &Addr,
+        expiration: Expiration,
+    ) -> StdResult<()> {
+        AccessControl::grant_role_until(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role.as_str(),
+            grant_to_address,
+            expiration,
+        )
+    }
+
+    pub fn propose_role_grant(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+        grant_to_address: &Addr,
+        expiration: Expiration,
+    ) -> StdResult<()> {
+        AccessControl::propose_role_grant(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role.as_str(),
+            grant_to_address,
+            expiration,
+        )
+    }
+
+    pub fn accept_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+    ) -> StdResult<()> {
+        AccessControl::accept_role(storage, env, response_handler, sender, role.as_str())
+    }
+
+    pub fn cancel_pending_role_grant(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+        grant_to_address: &Addr,
+    ) -> StdResult<()> {
+        AccessControl::cancel_pending_role_grant(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role.as_str(),
+            grant_to_address,
+        )
+    }
+
+    pub fn revoke_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+        address_to_revoke: &Addr,
+    ) -> StdResult<()> {
+        AccessControl::revoke_role(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role.as_str(),
+            address_to_revoke,
+        )
+    }
+
+    pub fn renounce_role(
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+    ) -> StdResult<()> {
+        AccessControl::renounce_role(storage, response_handler, sender, role.as_str())
+    }
+
+    pub fn grant_roles(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        grants: Vec<(R, Addr)>,
+    ) -> StdResult<()> {
+        AccessControl::grant_roles(
+            storage,
+            env,
+            response_handler,
+            sender,
+            to_role_strings(grants),
+        )
+    }
+
+    pub fn revoke_roles(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        revocations: Vec<(R, Addr)>,
+    ) -> StdResult<()> {
+        AccessControl::revoke_roles(
+            storage,
+            env,
+            response_handler,
+            sender,
+            to_role_strings(revocations),
+        )
+    }
+
+    pub fn change_admin_role(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+        new_admin_role: R,
+    ) -> StdResult<()> {
+        AccessControl::change_admin_role(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role.as_str(),
+            new_admin_role.as_str(),
+        )
+    }
+
+    pub fn set_min_role_holders(
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        sender: &Addr,
+        role: R,
+        min_holders: u32,
+    ) -> StdResult<()> {
+        AccessControl::set_min_role_holders(
+            storage,
+            env,
+            response_handler,
+            sender,
+            role.as_str(),
+            min_holders,
+        )
+    }
+
+    pub fn ensure_has_role(
+        storage: &dyn Storage,
+        env: &Env,
+        role: R,
+        address: &Addr,
+    ) -> StdResult<()> {
+        AccessControl::ensure_has_role(storage, env, role.as_str(), address)
+    }
+
+    pub fn ensure_has_role_or_superadmin(
+        deps: &Deps,
+        env: &Env,
+        role: R,
+        address: &Addr,
+    ) -> StdResult<()> {
+        AccessControl::ensure_has_role_or_superadmin(deps, env, role.as_str(), address)
+    }
+
+    pub fn ensure_has_any_role(
+        storage: &dyn Storage,
+        env: &Env,
+        roles: &[R],
+        address: &Addr,
+    ) -> StdResult<()> {
+        AccessControl::ensure_has_any_role(
+            storage,
+            env,
+            roles.iter().map(Role::as_str).collect(),
+            address,
+        )
+    }
+
+    pub fn has_role(storage: &dyn Storage, env: &Env, role: R, address: &Addr) -> bool {
+        AccessControl::has_role(storage, env, role.as_str(), address)
+    }
+
+    pub fn role_expiration(
+        storage: &dyn Storage,
+        role: R,
+        address: &Addr,
+    ) -> StdResult<Option<Expiration>> {
+        AccessControl::role_expiration(storage, role.as_str(), address)
+    }
+
+    pub fn pending_role_grant(
+        storage: &dyn Storage,
+        role: R,
+        address: &Addr,
+    ) -> StdResult<Option<PendingRoleGrant>> {
+        AccessControl::pending_role_grant(storage, role.as_str(), address)
+    }
+
+    pub fn role_info(storage: &dyn Storage, role: R) -> StdResult<RoleData> {
+        AccessControl::role_info(storage, role.as_str())
+    }
+
+    pub fn role_member_count(storage: &dyn Storage, role: R) -> StdResult<u32> {
+        AccessControl::role_member_count(storage, role.as_str())
+    }
+
+    pub fn min_role_holders(storage: &dyn Storage, role: R) -> StdResult<u32> {
+        AccessControl::min_role_holders(storage, role.as_str())
+    }
+}
+
+fn to_role_strings<R: Role>(changes: Vec<(R, Addr)>) -> Vec<(String, Addr)> {
+    changes
+        .into_iter()
+        .map(|(role, address)| (role.as_str().to_string(), address))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::{handle_access_control_execute_msg, AccessControlExecuteMsg};
+    use crate::define_roles;
+    use crate::testing::helpers::{assert_err, deps_with_creator};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    define_roles! {
+        enum TestRole {
+            Admin => DEFAULT_ADMIN_ROLE,
+            Minter => "minter",
+        }
+    }
+
+    #[test]
+    fn test_role_registry() {
+        assert_eq!(TestRole::Minter.as_str(), "minter");
+        assert_eq!(TestRole::from_role_str("minter"), Some(TestRole::Minter));
+        assert_eq!(TestRole::from_role_str("mintre"), None);
+
+        assert!(ensure_known_role::<TestRole>("minter").is_ok());
+        assert!(ensure_known_role::<TestRole>(DEFAULT_ADMIN_ROLE).is_ok());
+        assert_err(
+            &ensure_known_role::<TestRole>("mintre"),
+            &unknown_role_error("mintre"),
+        );
+    }
+
+    #[test]
+    fn test_typed_access_control() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            TestRole::Admin.as_str(),
+            &creator
+        )
+        .is_ok());
+
+        assert!(TypedAccessControl::grant_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            TestRole::Minter,
+            &user
+        )
+        .is_ok());
+
+        assert!(TypedAccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            TestRole::Minter,
+            &user
+        ));
+        assert!(TypedAccessControl::ensure_has_any_role(
+            deps.as_ref().storage,
+            &env,
+            &[TestRole::Admin, TestRole::Minter],
+            &user
+        )
+        .is_ok());
+
+        // Misspelled role is rejected before dispatch
+        assert_err(
+            &handle_access_control_execute_msg::<TestRole>(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(creator.as_str(), &[]),
+                AccessControlExecuteMsg::GrantRole {
+                    role: "mintre".to_string(),
+                    addr: user.clone(),
+                },
+            ),
+            &unknown_role_error("mintre"),
+        );
+
+        assert!(handle_access_control_execute_msg::<TestRole>(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            AccessControlExecuteMsg::RevokeRole {
+                role: "minter".to_string(),
+                addr: user.clone(),
+            },
+        )
+        .is_ok());
+        assert!(!TypedAccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            TestRole::Minter,
+            &user
+        ));
+
+        assert!(TypedAccessControl::create_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            TestRole::Minter,
+            TestRole::Admin,
+            Some("Mints tokens".to_string())
+        )
+        .is_ok());
+        assert_eq!(
+            TypedAccessControl::role_info(deps.as_ref().storage, TestRole::Minter)
+                .unwrap()
+                .description,
+            Some("Mints tokens".to_string())
+        );
+
+        assert!(TypedAccessControl::grant_roles(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            vec![(TestRole::Minter, user.clone())]
+        )
+        .is_ok());
+        assert_eq!(
+            TypedAccessControl::role_member_count(deps.as_ref().storage, TestRole::Minter).unwrap(),
+            1
+        );
+        assert!(TypedAccessControl::revoke_roles(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            vec![(TestRole::Minter, user.clone())]
+        )
+        .is_ok());
+
+        assert!(TypedAccessControl::delete_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            TestRole::Minter
+        )
+        .is_ok());
+
+        assert!(TypedAccessControl::propose_role_grant(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &creator,
+            TestRole::Admin,
+            &user,
+            Expiration::Never {}
+        )
+        .is_ok());
+        assert!(TypedAccessControl::accept_role(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &user,
+            TestRole::Admin
+        )
+        .is_ok());
+        assert!(TypedAccessControl::has_role(
+            deps.as_ref().storage,
+            &env,
+            TestRole::Admin,
+            &user
+        ));
+    }
+}
@@ -1 +1,2 @@
 pub mod iteration_guard;
+pub mod pagination;
@@ -0,0 +1,22 @@
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 30;
+
+// Clamp user supplied page size to the allowed maximum
+pub fn query_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_limit() {
+        assert_eq!(query_limit(None), DEFAULT_QUERY_LIMIT as usize);
+        assert_eq!(query_limit(Some(5)), 5);
+        assert_eq!(
+            query_limit(Some(MAX_QUERY_LIMIT + 1)),
+            MAX_QUERY_LIMIT as usize
+        );
+    }
+}
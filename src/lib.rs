@@ -1,3 +1,6 @@
+// Lets the generated `::cw_fetch_std::...` paths resolve inside this crate as well
+extern crate self as cw_fetch_std;
+
 pub mod access_control;
 pub mod balance;
 pub mod crypto;
@@ -7,5 +10,7 @@ pub mod pausing;
 pub mod permissions;
 pub mod storage;
 pub mod testing;
+pub mod timelock;
 
 pub use query_responses_hybrid_derive;
+pub use query_responses_hybrid_derive::requires;
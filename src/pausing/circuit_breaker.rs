@@ -0,0 +1,324 @@
+use crate::events::ResponseHandler;
+use crate::pausing::events::{CircuitBreakerConfiguredEvent, CircuitBreakerTrippedEvent};
+use crate::pausing::{
+    ensure_block_pause_extends, invalid_circuit_breaker_config_error, is_paused, pause_contract,
+    PauseDetails,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Env, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+
+const CIRCUIT_BREAKER_PAUSE_REASON: &str = "circuit_breaker_tripped";
+
+#[cw_serde]
+pub struct CircuitBreakerConfig {
+    // Maximum total flow allowed within the window
+    pub limit: Uint128,
+    // Number of blocks in the rolling window, including the current one
+    pub window_blocks: u64,
+}
+
+// Pauses the contract when recorded flows exceed a limit within a rolling block window
+pub struct CircuitBreaker<'a> {
+    config: Item<'a, CircuitBreakerConfig>,
+    flows: Map<'a, u64, Uint128>,
+    // Sum of all entries in flows, kept in sync on record and prune
+    total_flow: Item<'a, Uint128>,
+}
+
+impl<'a> CircuitBreaker<'a> {
+    pub const fn new(
+        config_namespace: &'a str,
+        flows_namespace: &'a str,
+        total_flow_namespace: &'a str,
+    ) -> Self {
+        CircuitBreaker {
+            config: Item::new(config_namespace),
+            flows: Map::new(flows_namespace),
+            total_flow: Item::new(total_flow_namespace),
+        }
+    }
+
+    pub fn configure(
+        &self,
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        limit: Uint128,
+        window_blocks: u64,
+    ) -> StdResult<()> {
+        // Breaker with an empty window or zero limit is rejected as misconfigured
+        if limit.is_zero() || window_blocks == 0 {
+            return Err(invalid_circuit_breaker_config_error());
+        }
+
+        response_handler.add_event(CircuitBreakerConfiguredEvent {
+            limit: &limit,
+            window_blocks: &window_blocks,
+        });
+        self.config.save(
+            storage,
+            &CircuitBreakerConfig {
+                limit,
+                window_blocks,
+            },
+        )
+    }
+
+    pub fn config(&self, storage: &dyn Storage) -> StdResult<Option<CircuitBreakerConfig>> {
+        self.config.may_load(storage)
+    }
+
+    // Total flow recorded within the current window
+    pub fn window_flow(&self, storage: &dyn Storage, env: &Env) -> StdResult<Uint128> {
+        let config = match self.config(storage)? {
+            Some(config) => config,
+            None => return Ok(Uint128::zero()),
+        };
+
+        let stale_flow = self
+            .stale_flows(storage, window_start(env, &config))?
+            .into_iter()
+            .try_fold(Uint128::zero(), |total, (_, amount)| {
+                total.checked_add(amount)
+            })?;
+        Ok(self.total_flow(storage)?.checked_sub(stale_flow)?)
+    }
+
+    // Returns Ok(true) if the flow would exceed the limit. Such flow is not recorded and the
+    // contract is paused, callers should reject the transfer without returning an error,
+    // otherwise the pause is reverted.
+    pub fn record_flow(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        response_handler: &mut ResponseHandler,
+        amount: Uint128,
+    ) -> StdResult<bool> {
+        let config = match self.config(storage)? {
+            Some(config) => config,
+            None => return Ok(false),
+        };
+
+        self.prune(storage, window_start(env, &config))?;
+
+        let total_flow = self.total_flow(storage)?;
+        let flow = total_flow.checked_add(amount)?;
+        if flow <= config.limit {
+            let height = env.block.height;
+            let block_flow = self.flows.may_load(storage, height)?.unwrap_or_default();
+            self.flows
+                .save(storage, height, &block_flow.checked_add(amount)?)?;
+            self.total_flow.save(storage, &flow)?;
+            return Ok(false);
+        }
+
+        if is_paused(storage, env)? {
+            return Ok(true);
+        }
+
+        // Open-ended pause from the current block, so a configured window is only ever extended
+        let height = env.block.height;
+        ensure_block_pause_extends(storage, env, height, None)?;
+
+        response_handler.add_event(CircuitBreakerTrippedEvent {
+            flow: &flow,
+            limit: &config.limit,
+            window_blocks: &config.window_blocks,
+        });
+        pause_contract(
+            storage,
+            response_handler,
+            height,
+            None,
+            PauseDetails {
+                reason: Some(CIRCUIT_BREAKER_PAUSE_REASON.to_string()),
+                paused_by: None,
+            },
+        )?;
+
+        Ok(true)
+    }
+
+    fn total_flow(&self, storage: &dyn Storage) -> StdResult<Uint128> {
+        Ok(self.total_flow.may_load(storage)?.unwrap_or_default())
+    }
+
+    // Every record prunes the whole stale range, so at most one window worth of entries
+    // is ever left behind
+    fn stale_flows(
+        &self,
+        storage: &dyn Storage,
+        window_start: u64,
+    ) -> StdResult<Vec<(u64, Uint128)>> {
+        self.flows
+            .range(
+                storage,
+                None,
+                Some(Bound::exclusive(window_start)),
+                Order::Ascending,
+            )
+            .collect()
+    }
+
+    fn prune(&self, storage: &mut dyn Storage, window_start: u64) -> StdResult<()> {
+        let stale_flows = self.stale_flows(storage, window_start)?;
+        if stale_flows.is_empty() {
+            return Ok(());
+        }
+
+        let mut total_flow = self.total_flow(storage)?;
+        for (height, amount) in stale_flows {
+            total_flow = total_flow.checked_sub(amount)?;
+            self.flows.remove(storage, height);
+        }
+        self.total_flow.save(storage, &total_flow)
+    }
+}
+
+fn window_start(env: &Env, config: &CircuitBreakerConfig) -> u64 {
+    (env.block.height + 1).saturating_sub(config.window_blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pausing::{contract_paused_error, ensure_not_paused, pause_info};
+    use crate::testing::helpers::{assert_err, mock_env_with_height};
+    use cosmwasm_std::testing::mock_dependencies;
+
+    const BREAKER: CircuitBreaker =
+        CircuitBreaker::new("breaker_config", "breaker_flows", "breaker_total_flow");
+
+    #[test]
+    fn test_circuit_breaker() {
+        let mut deps = mock_dependencies();
+
+        // Unconfigured breaker never trips
+        assert!(!BREAKER
+            .record_flow(
+                deps.as_mut().storage,
+                &mock_env_with_height(1),
+                &mut ResponseHandler::default(),
+                Uint128::new(1000)
+            )
+            .unwrap());
+
+        for (limit, window_blocks) in [(Uint128::new(100), 0), (Uint128::zero(), 3)] {
+            assert_err(
+                &BREAKER.configure(
+                    deps.as_mut().storage,
+                    &mut ResponseHandler::default(),
+                    limit,
+                    window_blocks,
+                ),
+                &invalid_circuit_breaker_config_error(),
+            );
+        }
+        assert!(BREAKER.config(deps.as_ref().storage).unwrap().is_none());
+
+        assert!(BREAKER
+            .configure(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                Uint128::new(100),
+                3
+            )
+            .is_ok());
+
+        // Flows within the limit
+        for height in 10..13 {
+            assert!(!BREAKER
+                .record_flow(
+                    deps.as_mut().storage,
+                    &mock_env_with_height(height),
+                    &mut ResponseHandler::default(),
+                    Uint128::new(30)
+                )
+                .unwrap());
+        }
+
+        // Oldest flow left the window
+        let env = mock_env_with_height(13);
+        assert_eq!(
+            BREAKER.window_flow(deps.as_ref().storage, &env).unwrap(),
+            Uint128::new(60)
+        );
+        assert!(!BREAKER
+            .record_flow(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                Uint128::new(40)
+            )
+            .unwrap());
+        assert!(ensure_not_paused(deps.as_ref().storage, &env).is_ok());
+
+        // Exceeding the limit pauses the contract
+        let mut response_handler = ResponseHandler::default();
+        assert!(BREAKER
+            .record_flow(
+                deps.as_mut().storage,
+                &env,
+                &mut response_handler,
+                Uint128::new(1)
+            )
+            .unwrap());
+        assert_err(
+            &ensure_not_paused(deps.as_ref().storage, &env),
+            &contract_paused_error(),
+        );
+        assert_eq!(
+            pause_info(deps.as_ref().storage).unwrap().unwrap().reason,
+            Some(CIRCUIT_BREAKER_PAUSE_REASON.to_string())
+        );
+        assert_eq!(
+            response_handler.into_response().events[0].ty,
+            "circuit_breaker_tripped"
+        );
+
+        // Rejected flow is not recorded
+        assert_eq!(
+            BREAKER.window_flow(deps.as_ref().storage, &env).unwrap(),
+            Uint128::new(100)
+        );
+
+        // Further excess is rejected without re-pausing
+        let mut response_handler = ResponseHandler::default();
+        assert!(BREAKER
+            .record_flow(
+                deps.as_mut().storage,
+                &env,
+                &mut response_handler,
+                Uint128::new(1)
+            )
+            .unwrap());
+        assert!(response_handler.into_response().events.is_empty());
+
+        // All entries out of the window are pruned on the next record
+        let env = mock_env_with_height(100);
+        assert!(BREAKER
+            .window_flow(deps.as_ref().storage, &env)
+            .unwrap()
+            .is_zero());
+        assert!(!BREAKER
+            .record_flow(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                Uint128::new(100)
+            )
+            .unwrap());
+        assert_eq!(
+            BREAKER
+                .flows
+                .keys(deps.as_ref().storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap(),
+            vec![100]
+        );
+        assert_eq!(
+            BREAKER.window_flow(deps.as_ref().storage, &env).unwrap(),
+            Uint128::new(100)
+        );
+    }
+}
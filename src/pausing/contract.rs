@@ -0,0 +1,333 @@
+use crate::access_control::AccessControl;
+use crate::events::ResponseHandler;
+use crate::helpers::pagination::query_limit;
+use crate::pausing::{
+    cancel_scheduled_resume, ensure_block_pause_extends, ensure_time_pause_extends,
+    finalize_scheduled_resume, global_pause_window, global_time_pause_window, is_feature_paused,
+    is_paused, pause_contract, pause_contract_at_time, pause_feature, pause_info,
+    paused_since_block, paused_since_time, paused_until_block, paused_until_time,
+    range_feature_pauses, resume_contract, resume_delay, resume_feature, schedule_resume,
+    scheduled_resume, set_resume_delay, PauseDetails, PauseInfo, PauseWindow, TimePauseWindow,
+    GLOBAL_PAUSE_SCOPE,
+};
+use crate::permissions::ensure_super_admin;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, StdResult, Timestamp};
+
+#[cw_serde]
+pub struct QueryPausedSinceResponse {
+    pub since_block: Option<u64>,
+    pub until_block: Option<u64>,
+    pub since_time: Option<Timestamp>,
+    pub until_time: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct QueryPauseInfoResponse {
+    pub info: Option<PauseInfo>,
+}
+
+#[cw_serde]
+pub struct QueryResumeScheduleResponse {
+    pub delay_blocks: u64,
+    pub ready_at: Option<u64>,
+}
+
+#[cw_serde]
+pub struct QueryIsPausedResponse {
+    pub is_paused: bool,
+}
+
+#[cw_serde]
+pub struct QueryIsFeaturePausedResponse {
+    pub is_paused: bool,
+}
+
+#[cw_serde]
+pub struct FeaturePauseEntry {
+    pub feature: String,
+    pub window: PauseWindow,
+}
+
+#[cw_serde]
+pub struct QueryActivePausesResponse {
+    pub global: Option<PauseWindow>,
+    pub global_time: Option<TimePauseWindow>,
+    pub features: Vec<FeaturePauseEntry>,
+}
+
+pub fn query_paused_since(deps: Deps) -> StdResult<QueryPausedSinceResponse> {
+    Ok(QueryPausedSinceResponse {
+        since_block: paused_since_block(deps.storage)?,
+        until_block: paused_until_block(deps.storage)?,
+        since_time: paused_since_time(deps.storage)?,
+        until_time: paused_until_time(deps.storage)?,
+    })
+}
+
+pub fn query_pause_info(deps: Deps) -> StdResult<QueryPauseInfoResponse> {
+    Ok(QueryPauseInfoResponse {
+        info: pause_info(deps.storage)?,
+    })
+}
+
+pub fn query_resume_schedule(deps: Deps) -> StdResult<QueryResumeScheduleResponse> {
+    Ok(QueryResumeScheduleResponse {
+        delay_blocks: resume_delay(deps.storage)?,
+        ready_at: scheduled_resume(deps.storage)?,
+    })
+}
+
+pub fn query_is_paused(deps: Deps, env: Env) -> StdResult<QueryIsPausedResponse> {
+    Ok(QueryIsPausedResponse {
+        is_paused: is_paused(deps.storage, &env)?,
+    })
+}
+
+pub fn query_is_feature_paused(
+    deps: Deps,
+    env: Env,
+    feature: String,
+) -> StdResult<QueryIsFeaturePausedResponse> {
+    Ok(QueryIsFeaturePausedResponse {
+        is_paused: is_feature_paused(deps.storage, &env, &feature)?,
+    })
+}
+
+pub fn query_active_pauses(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QueryActivePausesResponse> {
+    let global =
+        global_pause_window(deps.storage)?.filter(|window| !window.is_expired(env.block.height));
+    let global_time =
+        global_time_pause_window(deps.storage)?.filter(|window| !window.is_expired(env.block.time));
+
+    let features = range_feature_pauses(deps.storage, &env, start_after.as_deref())
+        .take(query_limit(limit))
+        .map(|res| res.map(|(feature, window)| FeaturePauseEntry { feature, window }))
+        .collect::<StdResult<Vec<FeaturePauseEntry>>>()?;
+
+    Ok(QueryActivePausesResponse {
+        global,
+        global_time,
+        features,
+    })
+}
+
+// Holders of pauser_role and the super-admin can pause
+pub fn execute_pause(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pauser_role: &str,
+    since_block: Option<u64>,
+    until_block: Option<u64>,
+    reason: Option<String>,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, pauser_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+    let since_block = since_block.unwrap_or(env.block.height);
+    ensure_block_pause_extends(deps.storage, &env, since_block, until_block)?;
+
+    pause_contract(
+        deps.storage,
+        &mut response_handler,
+        since_block,
+        until_block,
+        PauseDetails {
+            reason,
+            paused_by: Some(info.sender.clone()),
+        },
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "pause")
+        .add_attribute("sender", info.sender)
+        .add_attribute("since_block", since_block.to_string()))
+}
+
+pub fn execute_pause_at_time(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pauser_role: &str,
+    since_time: Option<Timestamp>,
+    until_time: Option<Timestamp>,
+    reason: Option<String>,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, pauser_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+    let since_time = since_time.unwrap_or(env.block.time);
+    ensure_time_pause_extends(deps.storage, &env, since_time, until_time)?;
+
+    pause_contract_at_time(
+        deps.storage,
+        &mut response_handler,
+        since_time,
+        until_time,
+        PauseDetails {
+            reason,
+            paused_by: Some(info.sender.clone()),
+        },
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "pause_at_time")
+        .add_attribute("sender", info.sender)
+        .add_attribute("since_time", since_time.to_string()))
+}
+
+// Holders of pauser_role and the super-admin can resume
+pub fn execute_resume(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pauser_role: &str,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, pauser_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    // Resume has to go through the schedule while a delay is set
+    resume_contract(deps.storage, &env, &mut response_handler)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "resume")
+        .add_attribute("sender", info.sender))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_pause_feature(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pauser_role: &str,
+    feature: String,
+    since_block: Option<u64>,
+    until_block: Option<u64>,
+    reason: Option<String>,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, pauser_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+    let since_block = since_block.unwrap_or(env.block.height);
+    if feature == GLOBAL_PAUSE_SCOPE {
+        ensure_block_pause_extends(deps.storage, &env, since_block, until_block)?;
+    }
+
+    pause_feature(
+        deps.storage,
+        &mut response_handler,
+        &feature,
+        since_block,
+        until_block,
+        PauseDetails {
+            reason,
+            paused_by: Some(info.sender.clone()),
+        },
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "pause_feature")
+        .add_attribute("sender", info.sender)
+        .add_attribute("feature", feature)
+        .add_attribute("since_block", since_block.to_string()))
+}
+
+pub fn execute_resume_feature(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pauser_role: &str,
+    feature: String,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, pauser_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    resume_feature(deps.storage, &env, &mut response_handler, &feature)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "resume_feature")
+        .add_attribute("sender", info.sender)
+        .add_attribute("feature", feature))
+}
+
+pub fn execute_schedule_resume(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pauser_role: &str,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, pauser_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    let ready_at = schedule_resume(deps.storage, &env, &mut response_handler)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "schedule_resume")
+        .add_attribute("sender", info.sender)
+        .add_attribute("ready_at", ready_at.to_string()))
+}
+
+pub fn execute_cancel_scheduled_resume(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pauser_role: &str,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, pauser_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    cancel_scheduled_resume(deps.storage, &mut response_handler)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "cancel_scheduled_resume")
+        .add_attribute("sender", info.sender))
+}
+
+// Anyone can finalise a resume once its delay has elapsed
+pub fn execute_finalize_resume(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    finalize_scheduled_resume(deps.storage, &env, &mut response_handler)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "finalize_resume")
+        .add_attribute("sender", info.sender))
+}
+
+// Only the super-admin can change the delay, so a compromised pauser key can't shorten it
+pub fn execute_set_resume_delay(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delay_blocks: u64,
+) -> StdResult<Response> {
+    ensure_super_admin(&deps.as_ref(), &env, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    set_resume_delay(deps.storage, &mut response_handler, delay_blocks)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "set_resume_delay")
+        .add_attribute("sender", info.sender)
+        .add_attribute("delay_blocks", delay_blocks.to_string()))
+}
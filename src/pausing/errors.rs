@@ -6,3 +6,72 @@ const ERR_CONTRACT_PAUSED: &str = "[FET_ERR_CONTRACT_PAUSED] Contract is paused"
 pub fn contract_paused_error() -> StdError {
     StdError::generic_err(ERR_CONTRACT_PAUSED)
 }
+
+// pause window ends before it starts
+const ERR_INVALID_PAUSE_WINDOW: &str =
+    "[FET_ERR_INVALID_PAUSE_WINDOW] Pause end must be after pause start";
+
+pub fn invalid_pause_window_error() -> StdError {
+    StdError::generic_err(ERR_INVALID_PAUSE_WINDOW)
+}
+
+// feature paused
+const ERR_FEATURE_PAUSED: &str = "[FET_ERR_FEATURE_PAUSED] Feature is paused";
+
+pub fn feature_paused_error(feature: &str) -> StdError {
+    StdError::generic_err(format!("{}: {}", ERR_FEATURE_PAUSED, feature))
+}
+
+// resume requested while not paused
+const ERR_CONTRACT_NOT_PAUSED: &str = "[FET_ERR_CONTRACT_NOT_PAUSED] Contract is not paused";
+
+pub fn contract_not_paused_error() -> StdError {
+    StdError::generic_err(ERR_CONTRACT_NOT_PAUSED)
+}
+
+// no resume scheduled
+const ERR_NO_SCHEDULED_RESUME: &str = "[FET_ERR_NO_SCHEDULED_RESUME] No resume is scheduled";
+
+pub fn no_scheduled_resume_error() -> StdError {
+    StdError::generic_err(ERR_NO_SCHEDULED_RESUME)
+}
+
+// resume delay not elapsed
+const ERR_RESUME_NOT_READY: &str =
+    "[FET_ERR_RESUME_NOT_READY] Resume can't be executed before block";
+
+pub fn resume_not_ready_error(ready_at: u64) -> StdError {
+    StdError::generic_err(format!("{} {}", ERR_RESUME_NOT_READY, ready_at))
+}
+
+// immediate resume while a delay is configured
+const ERR_RESUME_DELAY_REQUIRED: &str =
+    "[FET_ERR_RESUME_DELAY_REQUIRED] Resume must be scheduled while a resume delay is set";
+
+pub fn resume_delay_required_error() -> StdError {
+    StdError::generic_err(ERR_RESUME_DELAY_REQUIRED)
+}
+
+// scheduled resume block doesn't fit in u64
+const ERR_RESUME_DELAY_OVERFLOW: &str =
+    "[FET_ERR_RESUME_DELAY_OVERFLOW] Resume delay overflows the block height";
+
+pub fn resume_delay_overflow_error() -> StdError {
+    StdError::generic_err(ERR_RESUME_DELAY_OVERFLOW)
+}
+
+// circuit breaker with an empty window or zero limit
+const ERR_INVALID_CIRCUIT_BREAKER_CONFIG: &str =
+    "[FET_ERR_INVALID_CIRCUIT_BREAKER_CONFIG] Circuit breaker limit and window must be non-zero";
+
+pub fn invalid_circuit_breaker_config_error() -> StdError {
+    StdError::generic_err(ERR_INVALID_CIRCUIT_BREAKER_CONFIG)
+}
+
+// re-pause that would end the active pause early while a resume delay is set
+const ERR_PAUSE_SHORTENED: &str =
+    "[FET_ERR_PAUSE_SHORTENED] Active pause can only be extended while a resume delay is set";
+
+pub fn pause_shortened_error() -> StdError {
+    StdError::generic_err(ERR_PAUSE_SHORTENED)
+}
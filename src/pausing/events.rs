@@ -1,6 +1,15 @@
+use crate::events::helpers::option_to_string;
 use crate::events::IntoEvent;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+
+// Only the trigger that was used (block or time) is set
 pub struct ContractPausedEvent<'a> {
-    pub since_block: &'a u64,
+    pub since_block: Option<&'a u64>,
+    pub until_block: Option<&'a u64>,
+    pub since_time: Option<&'a Timestamp>,
+    pub until_time: Option<&'a Timestamp>,
+    pub reason: Option<&'a str>,
+    pub paused_by: Option<&'a Addr>,
 }
 
 impl IntoEvent for ContractPausedEvent<'_> {
@@ -9,7 +18,21 @@ impl IntoEvent for ContractPausedEvent<'_> {
     }
 
     fn event_attributes(&self) -> Vec<(String, String)> {
-        vec![("since_block".to_string(), self.since_block.to_string())]
+        let mut attributes = vec![];
+        if let Some(since_block) = self.since_block {
+            attributes.push(("since_block".to_string(), since_block.to_string()));
+            attributes.push((
+                "until_block".to_string(),
+                option_to_string(self.until_block),
+            ));
+        }
+        if let Some(since_time) = self.since_time {
+            attributes.push(("since_time".to_string(), since_time.to_string()));
+            attributes.push(("until_time".to_string(), option_to_string(self.until_time)));
+        }
+        attributes.push(("reason".to_string(), option_to_string(self.reason)));
+        attributes.push(("paused_by".to_string(), option_to_string(self.paused_by)));
+        attributes
     }
 }
 
@@ -24,3 +47,150 @@ impl IntoEvent for ContractResumedEvent {
         vec![]
     }
 }
+
+pub struct PauseExemptRoleAddedEvent<'a> {
+    pub role: &'a str,
+}
+
+impl IntoEvent for PauseExemptRoleAddedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "pause_exempt_role_added"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![("role".to_string(), self.role.to_string())]
+    }
+}
+
+pub struct PauseExemptRoleRemovedEvent<'a> {
+    pub role: &'a str,
+}
+
+impl IntoEvent for PauseExemptRoleRemovedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "pause_exempt_role_removed"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![("role".to_string(), self.role.to_string())]
+    }
+}
+
+pub struct FeaturePausedEvent<'a> {
+    pub feature: &'a str,
+    pub since_block: &'a u64,
+    pub until_block: Option<&'a u64>,
+    pub reason: Option<&'a str>,
+    pub paused_by: Option<&'a Addr>,
+}
+
+impl IntoEvent for FeaturePausedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "feature_paused"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("feature".to_string(), self.feature.to_string()),
+            ("since_block".to_string(), self.since_block.to_string()),
+            (
+                "until_block".to_string(),
+                option_to_string(self.until_block),
+            ),
+            ("reason".to_string(), option_to_string(self.reason)),
+            ("paused_by".to_string(), option_to_string(self.paused_by)),
+        ]
+    }
+}
+
+pub struct FeatureResumedEvent<'a> {
+    pub feature: &'a str,
+}
+
+impl IntoEvent for FeatureResumedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "feature_resumed"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![("feature".to_string(), self.feature.to_string())]
+    }
+}
+
+pub struct CircuitBreakerConfiguredEvent<'a> {
+    pub limit: &'a Uint128,
+    pub window_blocks: &'a u64,
+}
+
+impl IntoEvent for CircuitBreakerConfiguredEvent<'_> {
+    fn event_name(&self) -> &str {
+        "circuit_breaker_configured"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("limit".to_string(), self.limit.to_string()),
+            ("window_blocks".to_string(), self.window_blocks.to_string()),
+        ]
+    }
+}
+
+pub struct CircuitBreakerTrippedEvent<'a> {
+    pub flow: &'a Uint128,
+    pub limit: &'a Uint128,
+    pub window_blocks: &'a u64,
+}
+
+impl IntoEvent for CircuitBreakerTrippedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "circuit_breaker_tripped"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("flow".to_string(), self.flow.to_string()),
+            ("limit".to_string(), self.limit.to_string()),
+            ("window_blocks".to_string(), self.window_blocks.to_string()),
+        ]
+    }
+}
+
+pub struct ResumeDelayUpdatedEvent<'a> {
+    pub delay_blocks: &'a u64,
+}
+
+impl IntoEvent for ResumeDelayUpdatedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "resume_delay_updated"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![("delay_blocks".to_string(), self.delay_blocks.to_string())]
+    }
+}
+
+pub struct ResumeScheduledEvent<'a> {
+    pub ready_at: &'a u64,
+}
+
+impl IntoEvent for ResumeScheduledEvent<'_> {
+    fn event_name(&self) -> &str {
+        "resume_scheduled"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![("ready_at".to_string(), self.ready_at.to_string())]
+    }
+}
+
+pub struct ResumeCancelledEvent {}
+
+impl IntoEvent for ResumeCancelledEvent {
+    fn event_name(&self) -> &str {
+        "resume_cancelled"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+}
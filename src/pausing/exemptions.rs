@@ -0,0 +1,134 @@
+use crate::access_control::AccessControl;
+use crate::events::ResponseHandler;
+use crate::pausing::contract_paused_error;
+use crate::pausing::events::{PauseExemptRoleAddedEvent, PauseExemptRoleRemovedEvent};
+use crate::pausing::is_paused;
+use crate::storage::StorageSet;
+use cosmwasm_std::{Addr, Env, StdResult, Storage};
+
+const PAUSE_EXEMPT_ROLES: StorageSet<String> = StorageSet::new("pause_exempt_roles");
+
+pub fn add_pause_exempt_role(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    role: &str,
+) -> StdResult<()> {
+    response_handler.add_event(PauseExemptRoleAddedEvent { role });
+    PAUSE_EXEMPT_ROLES.add(storage, &role.to_string())
+}
+
+pub fn remove_pause_exempt_role(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    role: &str,
+) {
+    response_handler.add_event(PauseExemptRoleRemovedEvent { role });
+    PAUSE_EXEMPT_ROLES.remove(storage, &role.to_string())
+}
+
+pub fn is_pause_exempt_role(storage: &dyn Storage, role: &str) -> bool {
+    PAUSE_EXEMPT_ROLES.has(storage, &role.to_string())
+}
+
+pub fn pause_exempt_roles(storage: &dyn Storage) -> StdResult<Vec<String>> {
+    PAUSE_EXEMPT_ROLES.get_all(storage)
+}
+
+// Passes while paused if the role is exempt and sender holds it
+pub fn ensure_not_paused_unless_role(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    role: &str,
+) -> StdResult<()> {
+    if !is_paused(storage, env)? {
+        return Ok(());
+    }
+
+    if is_pause_exempt_role(storage, role) && AccessControl::has_role(storage, env, role, sender) {
+        return Ok(());
+    }
+
+    Err(contract_paused_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pausing::{pause_contract, PauseDetails};
+    use crate::testing::helpers::{assert_err, mock_env_with_height};
+    use cosmwasm_std::testing::mock_dependencies;
+
+    const GUARDIAN_ROLE: &str = "guardian";
+
+    #[test]
+    fn test_pause_exempt_role() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_with_height(100);
+        let guardian = Addr::unchecked("guardian".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            GUARDIAN_ROLE,
+            &guardian
+        )
+        .is_ok());
+
+        // Not paused
+        assert!(
+            ensure_not_paused_unless_role(deps.as_ref().storage, &env, &user, GUARDIAN_ROLE)
+                .is_ok()
+        );
+
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            100,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+
+        // Role is not exempt yet
+        assert_err(
+            &ensure_not_paused_unless_role(deps.as_ref().storage, &env, &guardian, GUARDIAN_ROLE),
+            &contract_paused_error(),
+        );
+
+        assert!(add_pause_exempt_role(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            GUARDIAN_ROLE
+        )
+        .is_ok());
+        assert_eq!(
+            pause_exempt_roles(deps.as_ref().storage).unwrap(),
+            vec![GUARDIAN_ROLE.to_string()]
+        );
+
+        assert!(ensure_not_paused_unless_role(
+            deps.as_ref().storage,
+            &env,
+            &guardian,
+            GUARDIAN_ROLE
+        )
+        .is_ok());
+        assert_err(
+            &ensure_not_paused_unless_role(deps.as_ref().storage, &env, &user, GUARDIAN_ROLE),
+            &contract_paused_error(),
+        );
+
+        remove_pause_exempt_role(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            GUARDIAN_ROLE,
+        );
+        assert!(!is_pause_exempt_role(deps.as_ref().storage, GUARDIAN_ROLE));
+        assert_err(
+            &ensure_not_paused_unless_role(deps.as_ref().storage, &env, &guardian, GUARDIAN_ROLE),
+            &contract_paused_error(),
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::events::ResponseHandler;
+use crate::pausing::errors::{feature_paused_error, invalid_pause_window_error};
+use crate::pausing::events::{FeaturePausedEvent, FeatureResumedEvent};
+use crate::pausing::{
+    is_paused, pause_contract, paused_since_block, paused_since_time, paused_until_block,
+    paused_until_time, resume_contract, PauseDetails,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Env, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Map};
+
+// Scope name that maps onto the contract-wide pause
+pub const GLOBAL_PAUSE_SCOPE: &str = "global";
+
+const PAUSED_FEATURES: Map<&str, PauseWindow> = Map::new("paused_features");
+
+#[cw_serde]
+pub struct PauseWindow {
+    pub since_block: u64,
+    // First block at which the scope is no longer paused
+    pub until_block: Option<u64>,
+}
+
+impl PauseWindow {
+    pub fn is_active(&self, height: u64) -> bool {
+        height >= self.since_block && !self.is_expired(height)
+    }
+
+    pub fn is_expired(&self, height: u64) -> bool {
+        self.until_block.is_some_and(|until| height >= until)
+    }
+}
+
+#[cw_serde]
+pub struct TimePauseWindow {
+    pub since_time: Timestamp,
+    pub until_time: Option<Timestamp>,
+}
+
+impl TimePauseWindow {
+    pub fn is_expired(&self, time: Timestamp) -> bool {
+        self.until_time.is_some_and(|until| time >= until)
+    }
+}
+
+pub fn pause_feature(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    feature: &str,
+    since_block: u64,
+    until_block: Option<u64>,
+    details: PauseDetails,
+) -> StdResult<()> {
+    if feature == GLOBAL_PAUSE_SCOPE {
+        return pause_contract(storage, response_handler, since_block, until_block, details);
+    }
+
+    if let Some(until_block) = until_block {
+        if until_block <= since_block {
+            return Err(invalid_pause_window_error());
+        }
+    }
+
+    response_handler.add_event(FeaturePausedEvent {
+        feature,
+        since_block: &since_block,
+        until_block: until_block.as_ref(),
+        reason: details.reason.as_deref(),
+        paused_by: details.paused_by.as_ref(),
+    });
+    PAUSED_FEATURES.save(
+        storage,
+        feature,
+        &PauseWindow {
+            since_block,
+            until_block,
+        },
+    )
+}
+
+pub fn resume_feature(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+    feature: &str,
+) -> StdResult<()> {
+    if feature == GLOBAL_PAUSE_SCOPE {
+        return resume_contract(storage, env, response_handler);
+    }
+
+    response_handler.add_event(FeatureResumedEvent { feature });
+    PAUSED_FEATURES.remove(storage, feature);
+
+    Ok(())
+}
+
+pub fn feature_pause_window(
+    storage: &dyn Storage,
+    feature: &str,
+) -> StdResult<Option<PauseWindow>> {
+    if feature == GLOBAL_PAUSE_SCOPE {
+        return global_pause_window(storage);
+    }
+    PAUSED_FEATURES.may_load(storage, feature)
+}
+
+pub fn global_pause_window(storage: &dyn Storage) -> StdResult<Option<PauseWindow>> {
+    match paused_since_block(storage)? {
+        Some(since_block) => Ok(Some(PauseWindow {
+            since_block,
+            until_block: paused_until_block(storage)?,
+        })),
+        None => Ok(None),
+    }
+}
+
+pub fn global_time_pause_window(storage: &dyn Storage) -> StdResult<Option<TimePauseWindow>> {
+    match paused_since_time(storage)? {
+        Some(since_time) => Ok(Some(TimePauseWindow {
+            since_time,
+            until_time: paused_until_time(storage)?,
+        })),
+        None => Ok(None),
+    }
+}
+
+// Feature is paused if either its own scope or the whole contract is paused
+pub fn is_feature_paused(storage: &dyn Storage, env: &Env, feature: &str) -> StdResult<bool> {
+    if is_paused(storage, env)? {
+        return Ok(true);
+    }
+
+    Ok(PAUSED_FEATURES
+        .may_load(storage, feature)?
+        .is_some_and(|window| window.is_active(env.block.height)))
+}
+
+pub fn ensure_feature_not_paused(storage: &dyn Storage, env: &Env, feature: &str) -> StdResult<()> {
+    if is_feature_paused(storage, env, feature)? {
+        return Err(feature_paused_error(feature));
+    }
+    Ok(())
+}
+
+// Feature pauses that are ongoing or scheduled, skipping windows that already ended
+pub fn range_feature_pauses<'a>(
+    storage: &'a dyn Storage,
+    env: &Env,
+    start_after: Option<&str>,
+) -> Box<dyn Iterator<Item = StdResult<(String, PauseWindow)>> + 'a> {
+    let height = env.block.height;
+    Box::new(
+        PAUSED_FEATURES
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter(move |res| {
+                res.as_ref()
+                    .map_or(true, |(_, window)| !window.is_expired(height))
+            }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pausing::contract_paused_error;
+    use crate::testing::helpers::{assert_err, mock_env_with_height};
+    use cosmwasm_std::testing::mock_dependencies;
+
+    const DEPOSITS: &str = "deposits";
+    const WITHDRAWALS: &str = "withdrawals";
+
+    #[test]
+    fn test_feature_pausing() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_with_height(100);
+
+        assert!(pause_feature(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            DEPOSITS,
+            100,
+            Some(110),
+            PauseDetails::default()
+        )
+        .is_ok());
+
+        // Only the paused feature is affected
+        assert_err(
+            &ensure_feature_not_paused(deps.as_ref().storage, &env, DEPOSITS),
+            &feature_paused_error(DEPOSITS),
+        );
+        assert!(ensure_feature_not_paused(deps.as_ref().storage, &env, WITHDRAWALS).is_ok());
+        assert!(!is_paused(deps.as_ref().storage, &env).unwrap());
+
+        // Window ends automatically
+        assert!(
+            !is_feature_paused(deps.as_ref().storage, &mock_env_with_height(110), DEPOSITS)
+                .unwrap()
+        );
+
+        // Active pauses skip expired windows
+        let pauses = range_feature_pauses(deps.as_ref().storage, &env, None)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(pauses.len(), 1);
+        assert_eq!(pauses[0].0, DEPOSITS);
+        assert!(
+            range_feature_pauses(deps.as_ref().storage, &mock_env_with_height(110), None)
+                .next()
+                .is_none()
+        );
+
+        assert!(resume_feature(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            DEPOSITS,
+        )
+        .is_ok());
+        assert!(ensure_feature_not_paused(deps.as_ref().storage, &env, DEPOSITS).is_ok());
+
+        // Global scope pauses every feature
+        assert!(pause_feature(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            GLOBAL_PAUSE_SCOPE,
+            100,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+        assert_err(
+            &ensure_feature_not_paused(deps.as_ref().storage, &env, WITHDRAWALS),
+            &feature_paused_error(WITHDRAWALS),
+        );
+        assert_err(
+            &crate::pausing::ensure_not_paused(deps.as_ref().storage, &env),
+            &contract_paused_error(),
+        );
+        assert_eq!(
+            feature_pause_window(deps.as_ref().storage, GLOBAL_PAUSE_SCOPE).unwrap(),
+            Some(PauseWindow {
+                since_block: 100,
+                until_block: None
+            })
+        );
+
+        assert!(resume_feature(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            GLOBAL_PAUSE_SCOPE,
+        )
+        .is_ok());
+        assert!(!is_feature_paused(deps.as_ref().storage, &env, WITHDRAWALS).unwrap());
+    }
+}
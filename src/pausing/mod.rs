@@ -1,8 +1,37 @@
+mod circuit_breaker;
+mod contract;
 mod errors;
 mod events;
+mod exemptions;
+mod features;
+mod msg;
+mod resume_delay;
 mod storage;
 
-pub use errors::contract_paused_error;
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+pub use contract::*;
+pub use errors::{
+    contract_not_paused_error, contract_paused_error, feature_paused_error,
+    invalid_circuit_breaker_config_error, invalid_pause_window_error, no_scheduled_resume_error,
+    pause_shortened_error, resume_delay_overflow_error, resume_delay_required_error,
+    resume_not_ready_error,
+};
+pub use exemptions::{
+    add_pause_exempt_role, ensure_not_paused_unless_role, is_pause_exempt_role, pause_exempt_roles,
+    remove_pause_exempt_role,
+};
+pub use features::{
+    ensure_feature_not_paused, feature_pause_window, global_pause_window, global_time_pause_window,
+    is_feature_paused, pause_feature, range_feature_pauses, resume_feature, PauseWindow,
+    TimePauseWindow, GLOBAL_PAUSE_SCOPE,
+};
+pub use msg::*;
+pub use resume_delay::{
+    cancel_scheduled_resume, ensure_block_pause_extends, ensure_time_pause_extends,
+    finalize_scheduled_resume, resume_delay, schedule_resume, scheduled_resume, set_resume_delay,
+};
 pub use storage::{
-    ensure_not_paused, is_paused, pause_contract, paused_since_block, resume_contract,
+    ensure_not_paused, is_paused, pause_contract, pause_contract_at_time, pause_details,
+    pause_info, paused_since_block, paused_since_time, paused_until_block, paused_until_time,
+    resume_contract, PauseDetails, PauseInfo,
 };
@@ -0,0 +1,433 @@
+use crate::pausing::{
+    execute_cancel_scheduled_resume, execute_finalize_resume, execute_pause, execute_pause_at_time,
+    execute_pause_feature, execute_resume, execute_resume_feature, execute_schedule_resume,
+    execute_set_resume_delay, query_active_pauses, query_is_feature_paused, query_is_paused,
+    query_pause_info, query_paused_since, query_resume_schedule, QueryActivePausesResponse,
+    QueryIsFeaturePausedResponse, QueryIsPausedResponse, QueryPauseInfoResponse,
+    QueryPausedSinceResponse, QueryResumeScheduleResponse,
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Timestamp,
+};
+
+#[cw_serde]
+pub enum PausingExecuteMsg {
+    Pause {
+        since_block: Option<u64>,
+        until_block: Option<u64>,
+        reason: Option<String>,
+    },
+
+    PauseAtTime {
+        since_time: Option<Timestamp>,
+        until_time: Option<Timestamp>,
+        reason: Option<String>,
+    },
+
+    Resume {},
+
+    ScheduleResume {},
+
+    CancelScheduledResume {},
+
+    FinalizeResume {},
+
+    SetResumeDelay {
+        delay_blocks: u64,
+    },
+
+    PauseFeature {
+        feature: String,
+        since_block: Option<u64>,
+        until_block: Option<u64>,
+        reason: Option<String>,
+    },
+
+    ResumeFeature {
+        feature: String,
+    },
+}
+pub fn handle_pausing_execute_msg(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pauser_role: &str,
+    msg: PausingExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        PausingExecuteMsg::Pause {
+            since_block,
+            until_block,
+            reason,
+        } => execute_pause(
+            deps,
+            env,
+            info,
+            pauser_role,
+            since_block,
+            until_block,
+            reason,
+        ),
+        PausingExecuteMsg::PauseAtTime {
+            since_time,
+            until_time,
+            reason,
+        } => execute_pause_at_time(deps, env, info, pauser_role, since_time, until_time, reason),
+        PausingExecuteMsg::Resume {} => execute_resume(deps, env, info, pauser_role),
+        PausingExecuteMsg::ScheduleResume {} => {
+            execute_schedule_resume(deps, env, info, pauser_role)
+        }
+        PausingExecuteMsg::CancelScheduledResume {} => {
+            execute_cancel_scheduled_resume(deps, env, info, pauser_role)
+        }
+        PausingExecuteMsg::FinalizeResume {} => execute_finalize_resume(deps, env, info),
+        PausingExecuteMsg::SetResumeDelay { delay_blocks } => {
+            execute_set_resume_delay(deps, env, info, delay_blocks)
+        }
+        PausingExecuteMsg::PauseFeature {
+            feature,
+            since_block,
+            until_block,
+            reason,
+        } => execute_pause_feature(
+            deps,
+            env,
+            info,
+            pauser_role,
+            feature,
+            since_block,
+            until_block,
+            reason,
+        ),
+        PausingExecuteMsg::ResumeFeature { feature } => {
+            execute_resume_feature(deps, env, info, pauser_role, feature)
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PausingQueryMsg {
+    #[returns(QueryPausedSinceResponse)]
+    PausedSince {},
+
+    #[returns(QueryPauseInfoResponse)]
+    PauseInfo {},
+
+    #[returns(QueryResumeScheduleResponse)]
+    ResumeSchedule {},
+
+    #[returns(QueryIsPausedResponse)]
+    IsPaused {},
+
+    #[returns(QueryIsFeaturePausedResponse)]
+    IsFeaturePaused { feature: String },
+
+    #[returns(QueryActivePausesResponse)]
+    ActivePauses {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+pub fn handle_pausing_query_msg(deps: Deps, env: Env, msg: PausingQueryMsg) -> StdResult<Binary> {
+    match msg {
+        PausingQueryMsg::PausedSince {} => to_json_binary(&query_paused_since(deps)?),
+        PausingQueryMsg::PauseInfo {} => to_json_binary(&query_pause_info(deps)?),
+        PausingQueryMsg::ResumeSchedule {} => to_json_binary(&query_resume_schedule(deps)?),
+        PausingQueryMsg::IsPaused {} => to_json_binary(&query_is_paused(deps, env)?),
+        PausingQueryMsg::IsFeaturePaused { feature } => {
+            to_json_binary(&query_is_feature_paused(deps, env, feature)?)
+        }
+        PausingQueryMsg::ActivePauses { start_after, limit } => {
+            to_json_binary(&query_active_pauses(deps, env, start_after, limit)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::error::no_role_error;
+    use crate::access_control::AccessControl;
+    use crate::events::ResponseHandler;
+    use crate::pausing::{
+        is_paused, pause_shortened_error, resume_delay_required_error, GLOBAL_PAUSE_SCOPE,
+    };
+    use crate::permissions::not_super_admin_error;
+    use crate::testing::helpers::{assert_err, deps_with_creator, mock_env_with_height};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_json, Addr};
+
+    const PAUSER_ROLE: &str = "pauser";
+
+    #[test]
+    fn test_pause_resume_msgs() {
+        let creator = Addr::unchecked("owner".to_string());
+        let pauser = Addr::unchecked("pauser".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            PAUSER_ROLE,
+            &pauser
+        )
+        .is_ok());
+
+        assert_err(
+            &handle_pausing_execute_msg(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(user.as_str(), &[]),
+                PAUSER_ROLE,
+                PausingExecuteMsg::Pause {
+                    since_block: None,
+                    until_block: None,
+                    reason: None,
+                },
+            ),
+            &no_role_error(&user, Some(PAUSER_ROLE)),
+        );
+
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(pauser.as_str(), &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::Pause {
+                since_block: None,
+                until_block: None,
+                reason: Some("maintenance".to_string()),
+            },
+        )
+        .is_ok());
+
+        let res: QueryPausedSinceResponse = from_json(
+            handle_pausing_query_msg(deps.as_ref(), env.clone(), PausingQueryMsg::PausedSince {})
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res.since_block, Some(env.block.height));
+
+        // Pauser and reason are recorded
+        let res: QueryPauseInfoResponse = from_json(
+            handle_pausing_query_msg(deps.as_ref(), env.clone(), PausingQueryMsg::PauseInfo {})
+                .unwrap(),
+        )
+        .unwrap();
+        let info = res.info.unwrap();
+        assert_eq!(info.paused_by, Some(pauser.clone()));
+        assert_eq!(info.reason, Some("maintenance".to_string()));
+        assert!(is_paused(deps.as_ref().storage, &env).unwrap());
+
+        // Super-admin can resume without the role
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::Resume {},
+        )
+        .is_ok());
+
+        let res: QueryIsPausedResponse = from_json(
+            handle_pausing_query_msg(deps.as_ref(), env.clone(), PausingQueryMsg::IsPaused {})
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!res.is_paused);
+    }
+
+    #[test]
+    fn test_delayed_resume_msgs() {
+        let creator = Addr::unchecked("owner".to_string());
+        let guardian = Addr::unchecked("guardian".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            PAUSER_ROLE,
+            &guardian
+        )
+        .is_ok());
+
+        // Only the super-admin sets the delay
+        assert_err(
+            &handle_pausing_execute_msg(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(guardian.as_str(), &[]),
+                PAUSER_ROLE,
+                PausingExecuteMsg::SetResumeDelay { delay_blocks: 10 },
+            ),
+            &not_super_admin_error(),
+        );
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::SetResumeDelay { delay_blocks: 10 },
+        )
+        .is_ok());
+
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(guardian.as_str(), &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::Pause {
+                since_block: None,
+                until_block: None,
+                reason: None,
+            },
+        )
+        .is_ok());
+
+        // Immediate resume is refused
+        assert_err(
+            &handle_pausing_execute_msg(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(guardian.as_str(), &[]),
+                PAUSER_ROLE,
+                PausingExecuteMsg::Resume {},
+            ),
+            &resume_delay_required_error(),
+        );
+
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(guardian.as_str(), &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::ScheduleResume {},
+        )
+        .is_ok());
+
+        let res: QueryResumeScheduleResponse = from_json(
+            handle_pausing_query_msg(
+                deps.as_ref(),
+                env.clone(),
+                PausingQueryMsg::ResumeSchedule {},
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let ready_at = env.block.height + 10;
+        assert_eq!(res.ready_at, Some(ready_at));
+
+        // Anyone can finalise after the delay
+        let env_ready = mock_env_with_height(ready_at);
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env_ready.clone(),
+            mock_info("anyone", &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::FinalizeResume {},
+        )
+        .is_ok());
+        assert!(!is_paused(deps.as_ref().storage, &env_ready).unwrap());
+    }
+
+    #[test]
+    fn test_repause_cant_bypass_resume_delay() {
+        let creator = Addr::unchecked("owner".to_string());
+        let guardian = Addr::unchecked("guardian".to_string());
+
+        let env = mock_env();
+        let height = env.block.height;
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            PAUSER_ROLE,
+            &guardian
+        )
+        .is_ok());
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::SetResumeDelay { delay_blocks: 10 },
+        )
+        .is_ok());
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(guardian.as_str(), &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::Pause {
+                since_block: None,
+                until_block: Some(height + 100),
+                reason: None,
+            },
+        )
+        .is_ok());
+
+        let bypasses = [
+            // Pause moved to the future
+            PausingExecuteMsg::Pause {
+                since_block: Some(height + 1000),
+                until_block: None,
+                reason: None,
+            },
+            // Pause ending at the next block
+            PausingExecuteMsg::Pause {
+                since_block: None,
+                until_block: Some(height + 1),
+                reason: None,
+            },
+            // Time-based pause starting in the future
+            PausingExecuteMsg::PauseAtTime {
+                since_time: Some(env.block.time.plus_seconds(3600)),
+                until_time: None,
+                reason: None,
+            },
+            // Global scope maps onto the contract pause
+            PausingExecuteMsg::PauseFeature {
+                feature: GLOBAL_PAUSE_SCOPE.to_string(),
+                since_block: Some(height + 1000),
+                until_block: None,
+                reason: None,
+            },
+        ];
+        for msg in bypasses {
+            assert_err(
+                &handle_pausing_execute_msg(
+                    deps.as_mut(),
+                    env.clone(),
+                    mock_info(guardian.as_str(), &[]),
+                    PAUSER_ROLE,
+                    msg,
+                ),
+                &pause_shortened_error(),
+            );
+        }
+        assert!(is_paused(deps.as_ref().storage, &mock_env_with_height(height + 1)).unwrap());
+
+        // Extending the pause is allowed
+        assert!(handle_pausing_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(guardian.as_str(), &[]),
+            PAUSER_ROLE,
+            PausingExecuteMsg::Pause {
+                since_block: None,
+                until_block: None,
+                reason: None,
+            },
+        )
+        .is_ok());
+        assert!(is_paused(deps.as_ref().storage, &mock_env_with_height(height + 100)).unwrap());
+    }
+}
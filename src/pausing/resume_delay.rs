@@ -0,0 +1,337 @@
+use crate::events::ResponseHandler;
+use crate::pausing::errors::{
+    contract_not_paused_error, no_scheduled_resume_error, pause_shortened_error,
+    resume_delay_overflow_error, resume_delay_required_error, resume_not_ready_error,
+};
+use crate::pausing::events::{ResumeCancelledEvent, ResumeDelayUpdatedEvent, ResumeScheduledEvent};
+use crate::pausing::{
+    is_paused, pause_info, paused_until_block, paused_until_time, resume_contract,
+};
+use cosmwasm_std::{Env, StdResult, Storage, Timestamp};
+use cw_storage_plus::Item;
+
+// Number of blocks a scheduled resume has to wait before it can be executed
+const RESUME_DELAY: Item<u64> = Item::new("resume_delay");
+// Block at which the scheduled resume can be executed
+const SCHEDULED_RESUME: Item<u64> = Item::new("scheduled_resume");
+
+pub fn set_resume_delay(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    delay_blocks: u64,
+) -> StdResult<()> {
+    response_handler.add_event(ResumeDelayUpdatedEvent {
+        delay_blocks: &delay_blocks,
+    });
+
+    if delay_blocks == 0 {
+        RESUME_DELAY.remove(storage);
+        return Ok(());
+    }
+    RESUME_DELAY.save(storage, &delay_blocks)
+}
+
+pub fn resume_delay(storage: &dyn Storage) -> StdResult<u64> {
+    Ok(RESUME_DELAY.may_load(storage)?.unwrap_or_default())
+}
+
+pub fn scheduled_resume(storage: &dyn Storage) -> StdResult<Option<u64>> {
+    SCHEDULED_RESUME.may_load(storage)
+}
+
+// Rescheduling restarts the delay
+pub fn schedule_resume(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+) -> StdResult<u64> {
+    if pause_info(storage)?.is_none() {
+        return Err(contract_not_paused_error());
+    }
+
+    let ready_at = env
+        .block
+        .height
+        .checked_add(resume_delay(storage)?)
+        .ok_or_else(resume_delay_overflow_error)?;
+
+    response_handler.add_event(ResumeScheduledEvent {
+        ready_at: &ready_at,
+    });
+    SCHEDULED_RESUME.save(storage, &ready_at)?;
+
+    Ok(ready_at)
+}
+
+pub fn cancel_scheduled_resume(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+) -> StdResult<()> {
+    if scheduled_resume(storage)?.is_none() {
+        return Err(no_scheduled_resume_error());
+    }
+
+    response_handler.add_event(ResumeCancelledEvent {});
+    SCHEDULED_RESUME.remove(storage);
+
+    Ok(())
+}
+
+pub fn finalize_scheduled_resume(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+) -> StdResult<()> {
+    let ready_at = scheduled_resume(storage)?.ok_or_else(no_scheduled_resume_error)?;
+    if env.block.height < ready_at {
+        return Err(resume_not_ready_error(ready_at));
+    }
+
+    resume_contract(storage, env, response_handler)
+}
+
+// While a resume delay is set, an active pause can only be replaced by one which is
+// already in effect and doesn't end earlier, otherwise re-pausing would skip the delay
+pub fn ensure_block_pause_extends(
+    storage: &dyn Storage,
+    env: &Env,
+    since_block: u64,
+    until_block: Option<u64>,
+) -> StdResult<()> {
+    if resume_delay(storage)? == 0 || !is_paused(storage, env)? {
+        return Ok(());
+    }
+
+    let extends = since_block <= env.block.height
+        && match until_block {
+            Some(until_block) => {
+                paused_until_block(storage)?.is_some_and(|current| until_block >= current)
+            }
+            None => true,
+        };
+    if !extends {
+        return Err(pause_shortened_error());
+    }
+    Ok(())
+}
+
+pub fn ensure_time_pause_extends(
+    storage: &dyn Storage,
+    env: &Env,
+    since_time: Timestamp,
+    until_time: Option<Timestamp>,
+) -> StdResult<()> {
+    if resume_delay(storage)? == 0 || !is_paused(storage, env)? {
+        return Ok(());
+    }
+
+    let extends = since_time <= env.block.time
+        && match until_time {
+            Some(until_time) => {
+                paused_until_time(storage)?.is_some_and(|current| until_time >= current)
+            }
+            None => true,
+        };
+    if !extends {
+        return Err(pause_shortened_error());
+    }
+    Ok(())
+}
+
+// While a resume delay is set, the contract can only be resumed through a ready schedule
+pub(super) fn ensure_resume_allowed(storage: &dyn Storage, env: &Env) -> StdResult<()> {
+    if resume_delay(storage)? == 0 {
+        return Ok(());
+    }
+
+    match scheduled_resume(storage)? {
+        Some(ready_at) if env.block.height < ready_at => Err(resume_not_ready_error(ready_at)),
+        Some(_) => Ok(()),
+        None => Err(resume_delay_required_error()),
+    }
+}
+
+// Any new pause or resume invalidates a pending schedule
+pub(super) fn clear_scheduled_resume(storage: &mut dyn Storage) {
+    SCHEDULED_RESUME.remove(storage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pausing::{
+        is_paused, pause_contract, resume_feature, PauseDetails, GLOBAL_PAUSE_SCOPE,
+    };
+    use crate::testing::helpers::{assert_err, mock_env_with_height};
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn test_scheduled_resume() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_with_height(100);
+
+        assert!(
+            set_resume_delay(deps.as_mut().storage, &mut ResponseHandler::default(), 10).is_ok()
+        );
+        assert_eq!(resume_delay(deps.as_ref().storage).unwrap(), 10);
+
+        // Nothing to resume
+        assert_err(
+            &schedule_resume(deps.as_mut().storage, &env, &mut ResponseHandler::default()),
+            &contract_not_paused_error(),
+        );
+
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            100,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+
+        assert_eq!(
+            schedule_resume(deps.as_mut().storage, &env, &mut ResponseHandler::default()).unwrap(),
+            110
+        );
+
+        // Delay has to pass
+        assert_err(
+            &finalize_scheduled_resume(
+                deps.as_mut().storage,
+                &mock_env_with_height(109),
+                &mut ResponseHandler::default(),
+            ),
+            &resume_not_ready_error(110),
+        );
+
+        // Cancelled resume can't be executed
+        assert!(
+            cancel_scheduled_resume(deps.as_mut().storage, &mut ResponseHandler::default()).is_ok()
+        );
+        assert_err(
+            &finalize_scheduled_resume(
+                deps.as_mut().storage,
+                &mock_env_with_height(110),
+                &mut ResponseHandler::default(),
+            ),
+            &no_scheduled_resume_error(),
+        );
+
+        // Pausing again drops the schedule
+        assert!(
+            schedule_resume(deps.as_mut().storage, &env, &mut ResponseHandler::default()).is_ok()
+        );
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            105,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+        assert!(scheduled_resume(deps.as_ref().storage).unwrap().is_none());
+
+        assert!(
+            schedule_resume(deps.as_mut().storage, &env, &mut ResponseHandler::default()).is_ok()
+        );
+        let env_ready = mock_env_with_height(110);
+        assert!(finalize_scheduled_resume(
+            deps.as_mut().storage,
+            &env_ready,
+            &mut ResponseHandler::default()
+        )
+        .is_ok());
+        assert!(!is_paused(deps.as_ref().storage, &env_ready).unwrap());
+        assert!(scheduled_resume(deps.as_ref().storage).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_direct_resume_respects_delay() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_with_height(100);
+
+        assert!(
+            set_resume_delay(deps.as_mut().storage, &mut ResponseHandler::default(), 10).is_ok()
+        );
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            100,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+
+        // Nothing scheduled
+        assert_err(
+            &resume_contract(deps.as_mut().storage, &env, &mut ResponseHandler::default()),
+            &resume_delay_required_error(),
+        );
+        assert_err(
+            &resume_feature(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                GLOBAL_PAUSE_SCOPE,
+            ),
+            &resume_delay_required_error(),
+        );
+
+        // Scheduled but not ready
+        assert!(
+            schedule_resume(deps.as_mut().storage, &env, &mut ResponseHandler::default()).is_ok()
+        );
+        assert_err(
+            &resume_feature(
+                deps.as_mut().storage,
+                &mock_env_with_height(109),
+                &mut ResponseHandler::default(),
+                GLOBAL_PAUSE_SCOPE,
+            ),
+            &resume_not_ready_error(110),
+        );
+        assert!(is_paused(deps.as_ref().storage, &env).unwrap());
+
+        // Ready schedule is consumed
+        let env_ready = mock_env_with_height(110);
+        assert!(resume_contract(
+            deps.as_mut().storage,
+            &env_ready,
+            &mut ResponseHandler::default()
+        )
+        .is_ok());
+        assert!(!is_paused(deps.as_ref().storage, &env_ready).unwrap());
+        assert!(scheduled_resume(deps.as_ref().storage).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_schedule_resume_overflow() {
+        let mut deps = mock_dependencies();
+
+        assert!(set_resume_delay(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            u64::MAX
+        )
+        .is_ok());
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            100,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+
+        assert_err(
+            &schedule_resume(
+                deps.as_mut().storage,
+                &mock_env_with_height(100),
+                &mut ResponseHandler::default(),
+            ),
+            &resume_delay_overflow_error(),
+        );
+        assert!(scheduled_resume(deps.as_ref().storage).unwrap().is_none());
+    }
+}
@@ -1,20 +1,93 @@
 use crate::events::ResponseHandler;
-use crate::pausing::contract_paused_error;
+use crate::pausing::errors::{contract_paused_error, invalid_pause_window_error};
 use crate::pausing::events::{ContractPausedEvent, ContractResumedEvent};
-use cosmwasm_std::{Env, StdResult, Storage};
+use crate::pausing::resume_delay::{clear_scheduled_resume, ensure_resume_allowed};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Env, StdResult, Storage, Timestamp};
 use cw_storage_plus::Item;
 
 const PAUSED_SINCE_BLOCK: Item<u64> = Item::new("paused_since");
+// First block at which the contract is no longer paused
+const PAUSED_UNTIL_BLOCK: Item<u64> = Item::new("paused_until");
+// Time-based pause, mutually exclusive with the block-based one
+const PAUSED_SINCE_TIME: Item<Timestamp> = Item::new("paused_since_time");
+const PAUSED_UNTIL_TIME: Item<Timestamp> = Item::new("paused_until_time");
+const PAUSE_DETAILS: Item<PauseDetails> = Item::new("pause_details");
+
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseDetails {
+    pub reason: Option<String>,
+    pub paused_by: Option<Addr>,
+}
+
+// Full view of the current pause
+#[cw_serde]
+pub struct PauseInfo {
+    pub since_block: Option<u64>,
+    pub until_block: Option<u64>,
+    pub since_time: Option<Timestamp>,
+    pub until_time: Option<Timestamp>,
+    pub reason: Option<String>,
+    pub paused_by: Option<Addr>,
+}
 
 pub fn paused_since_block(storage: &dyn Storage) -> StdResult<Option<u64>> {
     PAUSED_SINCE_BLOCK.may_load(storage)
 }
 
+pub fn paused_until_block(storage: &dyn Storage) -> StdResult<Option<u64>> {
+    PAUSED_UNTIL_BLOCK.may_load(storage)
+}
+
+pub fn paused_since_time(storage: &dyn Storage) -> StdResult<Option<Timestamp>> {
+    PAUSED_SINCE_TIME.may_load(storage)
+}
+
+pub fn paused_until_time(storage: &dyn Storage) -> StdResult<Option<Timestamp>> {
+    PAUSED_UNTIL_TIME.may_load(storage)
+}
+
+pub fn pause_details(storage: &dyn Storage) -> StdResult<PauseDetails> {
+    Ok(PAUSE_DETAILS.may_load(storage)?.unwrap_or_default())
+}
+
+// None if no pause is configured
+pub fn pause_info(storage: &dyn Storage) -> StdResult<Option<PauseInfo>> {
+    let since_block = paused_since_block(storage)?;
+    let since_time = paused_since_time(storage)?;
+    if since_block.is_none() && since_time.is_none() {
+        return Ok(None);
+    }
+
+    let details = pause_details(storage)?;
+    Ok(Some(PauseInfo {
+        since_block,
+        until_block: paused_until_block(storage)?,
+        since_time,
+        until_time: paused_until_time(storage)?,
+        reason: details.reason,
+        paused_by: details.paused_by,
+    }))
+}
+
 pub fn is_paused(storage: &dyn Storage, env: &Env) -> StdResult<bool> {
     // Return error if contract is paused
     if let Some(paused_since_block) = paused_since_block(storage)? {
         if env.block.height >= paused_since_block {
-            return Ok(true);
+            // Pause window ends automatically at until_block
+            return match paused_until_block(storage)? {
+                Some(paused_until_block) => Ok(env.block.height < paused_until_block),
+                None => Ok(true),
+            };
+        }
+    }
+    if let Some(paused_since_time) = paused_since_time(storage)? {
+        if env.block.time >= paused_since_time {
+            return match paused_until_time(storage)? {
+                Some(paused_until_time) => Ok(env.block.time < paused_until_time),
+                None => Ok(true),
+            };
         }
     }
     Ok(false)
@@ -24,16 +97,87 @@ pub fn pause_contract(
     storage: &mut dyn Storage,
     response_handler: &mut ResponseHandler,
     since_block: u64,
+    until_block: Option<u64>,
+    details: PauseDetails,
 ) -> StdResult<()> {
+    if let Some(until_block) = until_block {
+        if until_block <= since_block {
+            return Err(invalid_pause_window_error());
+        }
+        PAUSED_UNTIL_BLOCK.save(storage, &until_block)?;
+    } else {
+        PAUSED_UNTIL_BLOCK.remove(storage);
+    }
+    remove_time_pause(storage);
+    clear_scheduled_resume(storage);
+
     response_handler.add_event(ContractPausedEvent {
-        since_block: &since_block,
+        since_block: Some(&since_block),
+        until_block: until_block.as_ref(),
+        since_time: None,
+        until_time: None,
+        reason: details.reason.as_deref(),
+        paused_by: details.paused_by.as_ref(),
     });
+    PAUSE_DETAILS.save(storage, &details)?;
     PAUSED_SINCE_BLOCK.save(storage, &since_block)
 }
 
-pub fn resume_contract(storage: &mut dyn Storage, response_handler: &mut ResponseHandler) {
+pub fn pause_contract_at_time(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    since_time: Timestamp,
+    until_time: Option<Timestamp>,
+    details: PauseDetails,
+) -> StdResult<()> {
+    if let Some(until_time) = until_time {
+        if until_time <= since_time {
+            return Err(invalid_pause_window_error());
+        }
+        PAUSED_UNTIL_TIME.save(storage, &until_time)?;
+    } else {
+        PAUSED_UNTIL_TIME.remove(storage);
+    }
+    remove_block_pause(storage);
+    clear_scheduled_resume(storage);
+
+    response_handler.add_event(ContractPausedEvent {
+        since_block: None,
+        until_block: None,
+        since_time: Some(&since_time),
+        until_time: until_time.as_ref(),
+        reason: details.reason.as_deref(),
+        paused_by: details.paused_by.as_ref(),
+    });
+    PAUSE_DETAILS.save(storage, &details)?;
+    PAUSED_SINCE_TIME.save(storage, &since_time)
+}
+
+// Fails while a resume delay is set and no scheduled resume is ready
+pub fn resume_contract(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+) -> StdResult<()> {
+    ensure_resume_allowed(storage, env)?;
+
     response_handler.add_event(ContractResumedEvent {});
-    PAUSED_SINCE_BLOCK.remove(storage)
+    remove_block_pause(storage);
+    remove_time_pause(storage);
+    clear_scheduled_resume(storage);
+    PAUSE_DETAILS.remove(storage);
+
+    Ok(())
+}
+
+fn remove_block_pause(storage: &mut dyn Storage) {
+    PAUSED_SINCE_BLOCK.remove(storage);
+    PAUSED_UNTIL_BLOCK.remove(storage)
+}
+
+fn remove_time_pause(storage: &mut dyn Storage) {
+    PAUSED_SINCE_TIME.remove(storage);
+    PAUSED_UNTIL_TIME.remove(storage)
 }
 
 pub fn ensure_not_paused(storage: &dyn Storage, env: &Env) -> StdResult<()> {
@@ -47,7 +191,7 @@ pub fn ensure_not_paused(storage: &dyn Storage, env: &Env) -> StdResult<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::testing::helpers::{assert_err, mock_env_with_height};
+    use crate::testing::helpers::{assert_err, mock_env_with_height, mock_env_with_time};
     use cosmwasm_std::testing::mock_dependencies;
 
     #[test]
@@ -67,7 +211,9 @@ mod tests {
         assert!(pause_contract(
             deps.as_mut().storage,
             &mut ResponseHandler::default(),
-            pause_height
+            pause_height,
+            None,
+            PauseDetails::default()
         )
         .is_ok());
 
@@ -92,7 +238,12 @@ mod tests {
         );
 
         // Resume contract
-        resume_contract(deps.as_mut().storage, &mut ResponseHandler::default());
+        assert!(resume_contract(
+            deps.as_mut().storage,
+            &env_after_paused,
+            &mut ResponseHandler::default()
+        )
+        .is_ok());
 
         // Contract is resumed
         assert!(paused_since_block(deps.as_ref().storage).unwrap().is_none());
@@ -107,4 +258,177 @@ mod tests {
         )
         .is_ok());
     }
+
+    #[test]
+    fn test_pause_window() {
+        let mut deps = mock_dependencies();
+        let since_block = 100u64;
+        let until_block = 110u64;
+
+        // Window must end after it starts
+        assert_err(
+            &pause_contract(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                since_block,
+                Some(since_block),
+                PauseDetails::default(),
+            ),
+            &invalid_pause_window_error(),
+        );
+
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            since_block,
+            Some(until_block),
+            PauseDetails::default()
+        )
+        .is_ok());
+        assert_eq!(
+            paused_until_block(deps.as_ref().storage).unwrap(),
+            Some(until_block)
+        );
+
+        assert!(!is_paused(
+            deps.as_ref().storage,
+            &mock_env_with_height(since_block - 1)
+        )
+        .unwrap());
+        assert!(is_paused(deps.as_ref().storage, &mock_env_with_height(since_block)).unwrap());
+        assert!(is_paused(
+            deps.as_ref().storage,
+            &mock_env_with_height(until_block - 1)
+        )
+        .unwrap());
+
+        // Resumes automatically
+        assert!(!is_paused(deps.as_ref().storage, &mock_env_with_height(until_block)).unwrap());
+
+        // Pausing without an end clears the previous one
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            since_block,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+        assert!(paused_until_block(deps.as_ref().storage).unwrap().is_none());
+        assert!(is_paused(deps.as_ref().storage, &mock_env_with_height(until_block)).unwrap());
+
+        assert!(resume_contract(
+            deps.as_mut().storage,
+            &mock_env_with_height(until_block),
+            &mut ResponseHandler::default()
+        )
+        .is_ok());
+        assert!(paused_until_block(deps.as_ref().storage).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_time_pausing() {
+        let mut deps = mock_dependencies();
+        let since_time = Timestamp::from_seconds(1_000);
+        let until_time = Timestamp::from_seconds(2_000);
+
+        assert_err(
+            &pause_contract_at_time(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                since_time,
+                Some(since_time),
+                PauseDetails::default(),
+            ),
+            &invalid_pause_window_error(),
+        );
+
+        // Block-based pause is replaced by the time-based one
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            1,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+        assert!(pause_contract_at_time(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            since_time,
+            Some(until_time),
+            PauseDetails::default()
+        )
+        .is_ok());
+        assert!(paused_since_block(deps.as_ref().storage).unwrap().is_none());
+        assert_eq!(
+            paused_since_time(deps.as_ref().storage).unwrap(),
+            Some(since_time)
+        );
+
+        assert!(!is_paused(
+            deps.as_ref().storage,
+            &mock_env_with_time(since_time.minus_seconds(1))
+        )
+        .unwrap());
+        assert_err(
+            &ensure_not_paused(deps.as_ref().storage, &mock_env_with_time(since_time)),
+            &contract_paused_error(),
+        );
+        assert!(!is_paused(deps.as_ref().storage, &mock_env_with_time(until_time)).unwrap());
+
+        assert!(resume_contract(
+            deps.as_mut().storage,
+            &mock_env_with_time(until_time),
+            &mut ResponseHandler::default()
+        )
+        .is_ok());
+        assert!(paused_since_time(deps.as_ref().storage).unwrap().is_none());
+        assert!(paused_until_time(deps.as_ref().storage).unwrap().is_none());
+        assert!(!is_paused(deps.as_ref().storage, &mock_env_with_time(since_time)).unwrap());
+    }
+
+    #[test]
+    fn test_pause_info() {
+        let mut deps = mock_dependencies();
+        let guardian = Addr::unchecked("guardian".to_string());
+
+        assert!(pause_info(deps.as_ref().storage).unwrap().is_none());
+
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            100,
+            Some(200),
+            PauseDetails {
+                reason: Some("incident".to_string()),
+                paused_by: Some(guardian.clone()),
+            },
+        )
+        .is_ok());
+
+        assert_eq!(
+            pause_info(deps.as_ref().storage).unwrap(),
+            Some(PauseInfo {
+                since_block: Some(100),
+                until_block: Some(200),
+                since_time: None,
+                until_time: None,
+                reason: Some("incident".to_string()),
+                paused_by: Some(guardian),
+            })
+        );
+
+        assert!(resume_contract(
+            deps.as_mut().storage,
+            &mock_env_with_height(100),
+            &mut ResponseHandler::default()
+        )
+        .is_ok());
+        assert!(pause_info(deps.as_ref().storage).unwrap().is_none());
+        assert_eq!(
+            pause_details(deps.as_ref().storage).unwrap(),
+            PauseDetails::default()
+        );
+    }
 }
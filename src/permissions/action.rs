@@ -0,0 +1,11 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, CosmosMsg};
+
+// Privileged action carried out once approved by threshold proposals or the timelock
+#[cw_serde]
+pub enum ProposalAction {
+    // Message dispatched by the contract on execution
+    Message { msg: CosmosMsg },
+    // Opaque payload interpreted by the contract on execution
+    Payload { payload: Binary },
+}
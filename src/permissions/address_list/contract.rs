@@ -0,0 +1,118 @@
+use crate::access_control::AccessControl;
+use crate::events::ResponseHandler;
+use crate::helpers::pagination::query_limit;
+use crate::permissions::address_list::{AddressList, AddressListMode};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+#[cw_serde]
+pub struct QueryAddressListModeResponse {
+    pub mode: AddressListMode,
+}
+
+#[cw_serde]
+pub struct QueryAddressListResponse {
+    pub addresses: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct QueryIsAllowedResponse {
+    pub is_allowed: bool,
+}
+
+pub fn query_address_list_mode(
+    deps: Deps,
+    list: &AddressList,
+) -> StdResult<QueryAddressListModeResponse> {
+    Ok(QueryAddressListModeResponse {
+        mode: list.mode(deps.storage)?,
+    })
+}
+
+pub fn query_address_list(
+    deps: Deps,
+    list: &AddressList,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<QueryAddressListResponse> {
+    let addresses = list
+        .range(deps.storage, start_after.as_ref())
+        .take(query_limit(limit))
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    Ok(QueryAddressListResponse { addresses })
+}
+
+pub fn query_is_allowed(
+    deps: Deps,
+    list: &AddressList,
+    addr: Addr,
+) -> StdResult<QueryIsAllowedResponse> {
+    Ok(QueryIsAllowedResponse {
+        is_allowed: list.is_allowed(deps.storage, &addr)?,
+    })
+}
+
+// Holders of admin_role and the super-admin can manage the list
+pub fn execute_set_address_list_mode(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    list: &AddressList,
+    admin_role: &str,
+    mode: AddressListMode,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, admin_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    list.set_mode(deps.storage, &mut response_handler, mode.clone())?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "set_address_list_mode")
+        .add_attribute("sender", info.sender)
+        .add_attribute("mode", mode.to_string()))
+}
+
+pub fn execute_add_to_address_list(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    list: &AddressList,
+    admin_role: &str,
+    addr: Addr,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, admin_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    list.add(deps.storage, &mut response_handler, &addr)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "add_to_address_list")
+        .add_attribute("sender", info.sender)
+        .add_attribute("addr", addr))
+}
+
+pub fn execute_remove_from_address_list(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    list: &AddressList,
+    admin_role: &str,
+    addr: Addr,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, admin_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    list.remove(deps.storage, &mut response_handler, &addr);
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "remove_from_address_list")
+        .add_attribute("sender", info.sender)
+        .add_attribute("addr", addr))
+}
@@ -0,0 +1,7 @@
+use cosmwasm_std::{Addr, StdError};
+
+const ERR_ADDRESS_NOT_ALLOWED: &str = "[FET_ERR_ADDRESS_NOT_ALLOWED] Address is not allowed";
+
+pub fn address_not_allowed_error(address: &Addr) -> StdError {
+    StdError::generic_err(format!("{}: {}", ERR_ADDRESS_NOT_ALLOWED, address))
+}
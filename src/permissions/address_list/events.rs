@@ -0,0 +1,57 @@
+use crate::events::IntoEvent;
+use crate::permissions::address_list::AddressListMode;
+use cosmwasm_std::Addr;
+
+pub struct AddressListModeUpdatedEvent<'a> {
+    pub list: &'a str,
+    pub mode: &'a AddressListMode,
+}
+
+impl IntoEvent for AddressListModeUpdatedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "address_list_mode_updated"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("list".to_string(), self.list.to_string()),
+            ("mode".to_string(), self.mode.to_string()),
+        ]
+    }
+}
+
+pub struct AddressListAddedEvent<'a> {
+    pub list: &'a str,
+    pub addr: &'a Addr,
+}
+
+impl IntoEvent for AddressListAddedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "address_list_added"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("list".to_string(), self.list.to_string()),
+            ("addr".to_string(), self.addr.to_string()),
+        ]
+    }
+}
+
+pub struct AddressListRemovedEvent<'a> {
+    pub list: &'a str,
+    pub addr: &'a Addr,
+}
+
+impl IntoEvent for AddressListRemovedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "address_list_removed"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("list".to_string(), self.list.to_string()),
+            ("addr".to_string(), self.addr.to_string()),
+        ]
+    }
+}
@@ -0,0 +1,189 @@
+use crate::events::ResponseHandler;
+use crate::permissions::address_list::error::address_not_allowed_error;
+use crate::permissions::address_list::events::{
+    AddressListAddedEvent, AddressListModeUpdatedEvent, AddressListRemovedEvent,
+};
+use crate::storage::StorageSet;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Item};
+use std::fmt;
+
+#[cw_serde]
+#[derive(Default)]
+pub enum AddressListMode {
+    // Only listed addresses are allowed
+    #[default]
+    Allowlist,
+    // Listed addresses are rejected
+    Denylist,
+}
+
+impl fmt::Display for AddressListMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressListMode::Allowlist => write!(f, "allowlist"),
+            AddressListMode::Denylist => write!(f, "denylist"),
+        }
+    }
+}
+
+pub struct AddressList<'a> {
+    name: &'a str,
+    mode: Item<'a, AddressListMode>,
+    addresses: StorageSet<'a, &'a Addr>,
+}
+
+impl<'a> AddressList<'a> {
+    // Name is used as the namespace of the addresses and in events
+    pub const fn new(name: &'a str, mode_namespace: &'a str) -> Self {
+        AddressList {
+            name,
+            mode: Item::new(mode_namespace),
+            addresses: StorageSet::new(name),
+        }
+    }
+
+    pub fn mode(&self, storage: &dyn Storage) -> StdResult<AddressListMode> {
+        Ok(self.mode.may_load(storage)?.unwrap_or_default())
+    }
+
+    pub fn set_mode(
+        &self,
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        mode: AddressListMode,
+    ) -> StdResult<()> {
+        response_handler.add_event(AddressListModeUpdatedEvent {
+            list: self.name,
+            mode: &mode,
+        });
+        self.mode.save(storage, &mode)
+    }
+
+    pub fn add(
+        &self,
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        address: &'a Addr,
+    ) -> StdResult<()> {
+        response_handler.add_event(AddressListAddedEvent {
+            list: self.name,
+            addr: address,
+        });
+        self.addresses.add(storage, &address)
+    }
+
+    pub fn remove(
+        &self,
+        storage: &mut dyn Storage,
+        response_handler: &mut ResponseHandler,
+        address: &'a Addr,
+    ) {
+        response_handler.add_event(AddressListRemovedEvent {
+            list: self.name,
+            addr: address,
+        });
+        self.addresses.remove(storage, &address)
+    }
+
+    pub fn contains(&self, storage: &dyn Storage, address: &'a Addr) -> bool {
+        self.addresses.has(storage, &address)
+    }
+
+    pub fn is_allowed(&self, storage: &dyn Storage, address: &'a Addr) -> StdResult<bool> {
+        let listed = self.contains(storage, address);
+        Ok(match self.mode(storage)? {
+            AddressListMode::Allowlist => listed,
+            AddressListMode::Denylist => !listed,
+        })
+    }
+
+    pub fn ensure_allowed(&self, storage: &dyn Storage, address: &'a Addr) -> StdResult<()> {
+        if !self.is_allowed(storage, address)? {
+            return Err(address_not_allowed_error(address));
+        }
+        Ok(())
+    }
+
+    pub fn range<'c>(
+        &self,
+        storage: &'c dyn Storage,
+        start_after: Option<&'a Addr>,
+    ) -> Box<dyn Iterator<Item = StdResult<Addr>> + 'c> {
+        self.addresses.range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::assert_err;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    const BETA_USERS: AddressList = AddressList::new("beta_users", "beta_users_mode");
+
+    #[test]
+    fn test_address_list() {
+        let mut deps = mock_dependencies();
+        let alice = Addr::unchecked("alice".to_string());
+        let bob = Addr::unchecked("bob".to_string());
+        let carol = Addr::unchecked("carol".to_string());
+
+        // Empty allowlist rejects everyone
+        assert_eq!(
+            BETA_USERS.mode(deps.as_ref().storage).unwrap(),
+            AddressListMode::Allowlist
+        );
+        assert_err(
+            &BETA_USERS.ensure_allowed(deps.as_ref().storage, &alice),
+            &address_not_allowed_error(&alice),
+        );
+
+        for addr in [&alice, &bob, &carol] {
+            assert!(BETA_USERS
+                .add(deps.as_mut().storage, &mut ResponseHandler::default(), addr)
+                .is_ok());
+        }
+        BETA_USERS.remove(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            &carol,
+        );
+
+        assert!(BETA_USERS
+            .ensure_allowed(deps.as_ref().storage, &alice)
+            .is_ok());
+        assert!(!BETA_USERS
+            .is_allowed(deps.as_ref().storage, &carol)
+            .unwrap());
+
+        // Pagination
+        let page = BETA_USERS
+            .range(deps.as_ref().storage, Some(&alice))
+            .collect::<StdResult<Vec<Addr>>>()
+            .unwrap();
+        assert_eq!(page, vec![bob.clone()]);
+
+        // Denylist inverts the check
+        assert!(BETA_USERS
+            .set_mode(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                AddressListMode::Denylist
+            )
+            .is_ok());
+        assert_err(
+            &BETA_USERS.ensure_allowed(deps.as_ref().storage, &alice),
+            &address_not_allowed_error(&alice),
+        );
+        assert!(BETA_USERS
+            .ensure_allowed(deps.as_ref().storage, &carol)
+            .is_ok());
+    }
+}
@@ -0,0 +1,10 @@
+mod contract;
+mod error;
+mod events;
+mod list;
+mod msg;
+
+pub use contract::*;
+pub use error::address_not_allowed_error;
+pub use list::{AddressList, AddressListMode};
+pub use msg::*;
@@ -0,0 +1,139 @@
+use crate::permissions::address_list::{
+    execute_add_to_address_list, execute_remove_from_address_list, execute_set_address_list_mode,
+    query_address_list, query_address_list_mode, query_is_allowed, AddressList, AddressListMode,
+    QueryAddressListModeResponse, QueryAddressListResponse, QueryIsAllowedResponse,
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+
+#[cw_serde]
+pub enum AddressListExecuteMsg {
+    SetAddressListMode { mode: AddressListMode },
+
+    AddToAddressList { addr: Addr },
+
+    RemoveFromAddressList { addr: Addr },
+}
+pub fn handle_address_list_execute_msg(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    list: &AddressList,
+    admin_role: &str,
+    msg: AddressListExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        AddressListExecuteMsg::SetAddressListMode { mode } => {
+            execute_set_address_list_mode(deps, env, info, list, admin_role, mode)
+        }
+        AddressListExecuteMsg::AddToAddressList { addr } => {
+            execute_add_to_address_list(deps, env, info, list, admin_role, addr)
+        }
+        AddressListExecuteMsg::RemoveFromAddressList { addr } => {
+            execute_remove_from_address_list(deps, env, info, list, admin_role, addr)
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AddressListQueryMsg {
+    #[returns(QueryAddressListModeResponse)]
+    AddressListMode {},
+
+    #[returns(QueryAddressListResponse)]
+    AddressList {
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryIsAllowedResponse)]
+    IsAllowed { addr: Addr },
+}
+pub fn handle_address_list_query_msg(
+    deps: Deps,
+    list: &AddressList,
+    msg: AddressListQueryMsg,
+) -> StdResult<Binary> {
+    match msg {
+        AddressListQueryMsg::AddressListMode {} => {
+            to_json_binary(&query_address_list_mode(deps, list)?)
+        }
+        AddressListQueryMsg::AddressList { start_after, limit } => {
+            to_json_binary(&query_address_list(deps, list, start_after, limit)?)
+        }
+        AddressListQueryMsg::IsAllowed { addr } => {
+            to_json_binary(&query_is_allowed(deps, list, addr)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::error::no_role_error;
+    use crate::testing::helpers::{assert_err, deps_with_creator};
+    use cosmwasm_std::from_json;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    const ADMIN_ROLE: &str = "list_admin";
+    const LIST: AddressList = AddressList::new("beta_users", "beta_users_mode");
+
+    #[test]
+    fn test_address_list_msgs() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert_err(
+            &handle_address_list_execute_msg(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(user.as_str(), &[]),
+                &LIST,
+                ADMIN_ROLE,
+                AddressListExecuteMsg::AddToAddressList { addr: user.clone() },
+            ),
+            &no_role_error(&user, Some(ADMIN_ROLE)),
+        );
+
+        assert!(handle_address_list_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            &LIST,
+            ADMIN_ROLE,
+            AddressListExecuteMsg::AddToAddressList { addr: user.clone() },
+        )
+        .is_ok());
+
+        let res: QueryAddressListResponse = from_json(
+            handle_address_list_query_msg(
+                deps.as_ref(),
+                &LIST,
+                AddressListQueryMsg::AddressList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res.addresses, vec![user.clone()]);
+
+        let res: QueryIsAllowedResponse = from_json(
+            handle_address_list_query_msg(
+                deps.as_ref(),
+                &LIST,
+                AddressListQueryMsg::IsAllowed { addr: user.clone() },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(res.is_allowed);
+    }
+}
@@ -1,8 +1,21 @@
+mod action;
+mod address_list;
+mod policy;
 mod private;
 mod superadmin;
+mod threshold;
+
+pub use crate::permissions::action::ProposalAction;
+
+pub use crate::permissions::address_list::*;
 
 pub use crate::permissions::superadmin::{
-    ensure_super_admin, is_super_admin, not_super_admin_error,
+    clear_super_admin_cache, ensure_super_admin, ensure_super_admin_cached, is_super_admin,
+    is_super_admin_cached, not_super_admin_error, sync_super_admin,
 };
 
-pub use crate::permissions::private::{ensure_private, not_self_contract_error};
+pub use crate::permissions::threshold::*;
+
+pub use crate::permissions::policy::{policy_not_satisfied_error, Policy};
+
+pub use crate::permissions::private::*;
@@ -0,0 +1,177 @@
+use crate::access_control::error::no_role_error;
+use crate::access_control::AccessControl;
+use crate::pausing::{contract_paused_error, is_paused};
+use crate::permissions::{
+    address_not_allowed_error, is_super_admin, is_super_admin_cached, not_self_contract_error,
+    not_super_admin_error,
+};
+use cosmwasm_std::{Addr, Deps, Env, StdError, StdResult};
+
+const ERR_POLICY_NOT_SATISFIED: &str = "[FET_ERR_POLICY_NOT_SATISFIED] Permission policy failed";
+
+// Declarative combination of the permission checks
+#[derive(Debug, Clone)]
+pub enum Policy<'a> {
+    AnyOf(Vec<Policy<'a>>),
+    AllOf(Vec<Policy<'a>>),
+    Role(&'a str),
+    SuperAdmin,
+    // Uses the synced super-admin, see sync_super_admin
+    SuperAdminCached,
+    SelfContract,
+    NotPaused,
+    Address(&'a Addr),
+}
+
+impl Policy<'_> {
+    pub fn ensure(&self, deps: Deps, env: &Env, sender: &Addr) -> StdResult<()> {
+        let failures = self.failures(deps, env, sender)?;
+        if !failures.is_empty() {
+            return Err(policy_not_satisfied_error(&failures));
+        }
+        Ok(())
+    }
+
+    // Messages of failed sub-policies, errors are returned only when a check can't be evaluated
+    fn failures(&self, deps: Deps, env: &Env, sender: &Addr) -> StdResult<Vec<String>> {
+        let failure = match self {
+            Policy::AllOf(policies) => {
+                let mut failures = vec![];
+                for policy in policies {
+                    failures.extend(policy.failures(deps, env, sender)?);
+                }
+                return Ok(failures);
+            }
+            Policy::AnyOf(policies) => {
+                let mut failures = vec![];
+                for policy in policies {
+                    let policy_failures = policy.failures(deps, env, sender)?;
+                    if policy_failures.is_empty() {
+                        return Ok(vec![]);
+                    }
+                    failures.extend(policy_failures);
+                }
+                return Ok(vec![format!("any of ({})", failures.join(" | "))]);
+            }
+            Policy::Role(role) => (!AccessControl::has_role(deps.storage, env, role, sender))
+                .then(|| no_role_error(sender, Some(role))),
+            Policy::SuperAdmin => {
+                (!is_super_admin(&deps, env, sender)?).then(not_super_admin_error)
+            }
+            Policy::SuperAdminCached => {
+                (!is_super_admin_cached(&deps, env, sender)?).then(not_super_admin_error)
+            }
+            Policy::SelfContract => (env.contract.address != sender).then(not_self_contract_error),
+            Policy::NotPaused => is_paused(deps.storage, env)?.then(contract_paused_error),
+            Policy::Address(address) => {
+                (*address != sender).then(|| address_not_allowed_error(sender))
+            }
+        };
+
+        Ok(failure.map(error_message).into_iter().collect())
+    }
+}
+
+pub fn policy_not_satisfied_error(failures: &[String]) -> StdError {
+    StdError::generic_err(format!(
+        "{}: {}",
+        ERR_POLICY_NOT_SATISFIED,
+        failures.join("; ")
+    ))
+}
+
+fn error_message(error: StdError) -> String {
+    match error {
+        StdError::GenericErr { msg, .. } => msg,
+        error => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::ResponseHandler;
+    use crate::pausing::{pause_contract, PauseDetails};
+    use crate::permissions::sync_super_admin;
+    use crate::testing::helpers::{assert_err, deps_with_creator};
+    use cosmwasm_std::testing::mock_env;
+
+    const MINTER_ROLE: &str = "minter";
+    const BURNER_ROLE: &str = "burner";
+
+    #[test]
+    fn test_policy() {
+        let creator = Addr::unchecked("creator".to_string());
+        let minter = Addr::unchecked("minter".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            MINTER_ROLE,
+            &minter
+        )
+        .is_ok());
+
+        let policy = Policy::AllOf(vec![
+            Policy::NotPaused,
+            Policy::AnyOf(vec![Policy::Role(MINTER_ROLE), Policy::SuperAdmin]),
+        ]);
+
+        assert!(policy.ensure(deps.as_ref(), &env, &minter).is_ok());
+        assert!(policy.ensure(deps.as_ref(), &env, &creator).is_ok());
+
+        // Failed sub-policies are listed
+        assert_err(
+            &policy.ensure(deps.as_ref(), &env, &user),
+            &policy_not_satisfied_error(&[format!(
+                "any of ({} | {})",
+                error_message(no_role_error(&user, Some(MINTER_ROLE))),
+                error_message(not_super_admin_error())
+            )]),
+        );
+
+        assert!(pause_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            env.block.height,
+            None,
+            PauseDetails::default()
+        )
+        .is_ok());
+
+        let policy = Policy::AllOf(vec![
+            Policy::NotPaused,
+            Policy::Role(BURNER_ROLE),
+            Policy::Address(&minter),
+        ]);
+        assert_err(
+            &policy.ensure(deps.as_ref(), &env, &minter),
+            &policy_not_satisfied_error(&[
+                error_message(contract_paused_error()),
+                error_message(no_role_error(&minter, Some(BURNER_ROLE))),
+            ]),
+        );
+
+        assert!(Policy::SelfContract
+            .ensure(deps.as_ref(), &env, &env.contract.address)
+            .is_ok());
+        assert!(Policy::Address(&minter)
+            .ensure(deps.as_ref(), &env, &user)
+            .is_err());
+
+        // Only the cached variant uses the synced super-admin
+        assert!(sync_super_admin(deps.as_mut(), &env).is_ok());
+        let mut other_env = mock_env();
+        other_env.contract.address = Addr::unchecked("unknown_contract".to_string());
+        assert!(Policy::SuperAdminCached
+            .ensure(deps.as_ref(), &other_env, &creator)
+            .is_ok());
+        assert!(Policy::SuperAdmin
+            .ensure(deps.as_ref(), &other_env, &creator)
+            .is_err());
+    }
+}
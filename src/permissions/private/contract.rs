@@ -0,0 +1,80 @@
+use crate::access_control::AccessControl;
+use crate::events::ResponseHandler;
+use crate::helpers::pagination::query_limit;
+use crate::permissions::private::{
+    add_trusted_contract, is_trusted_contract, range_trusted_contracts, remove_trusted_contract,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+#[cw_serde]
+pub struct QueryTrustedContractsResponse {
+    pub contracts: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct QueryIsTrustedContractResponse {
+    pub is_trusted: bool,
+}
+
+pub fn query_trusted_contracts(
+    deps: Deps,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<QueryTrustedContractsResponse> {
+    let contracts = range_trusted_contracts(deps.storage, start_after.as_ref())
+        .take(query_limit(limit))
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    Ok(QueryTrustedContractsResponse { contracts })
+}
+
+pub fn query_is_trusted_contract(
+    deps: Deps,
+    addr: Addr,
+) -> StdResult<QueryIsTrustedContractResponse> {
+    Ok(QueryIsTrustedContractResponse {
+        is_trusted: is_trusted_contract(deps.storage, &addr),
+    })
+}
+
+// Holders of admin_role and the super-admin can manage trusted contracts
+pub fn execute_add_trusted_contract(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    admin_role: &str,
+    addr: Addr,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, admin_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    add_trusted_contract(deps.storage, &mut response_handler, &addr)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "add_trusted_contract")
+        .add_attribute("sender", info.sender)
+        .add_attribute("addr", addr))
+}
+
+pub fn execute_remove_trusted_contract(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    admin_role: &str,
+    addr: Addr,
+) -> StdResult<Response> {
+    AccessControl::ensure_has_role_or_superadmin(&deps.as_ref(), &env, admin_role, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    remove_trusted_contract(deps.storage, &mut response_handler, &addr);
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "remove_trusted_contract")
+        .add_attribute("sender", info.sender)
+        .add_attribute("addr", addr))
+}
@@ -0,0 +1,13 @@
+use cosmwasm_std::StdError;
+
+const ERR_NOT_SELF_CONTRACT: &str = "[FET_ERR_NOT_SELF] Sender is not a self contract.";
+const ERR_NOT_SELF_OR_TRUSTED_CONTRACT: &str =
+    "[FET_ERR_NOT_SELF_OR_TRUSTED] Sender is not a self or trusted contract.";
+
+pub fn not_self_contract_error() -> StdError {
+    StdError::generic_err(ERR_NOT_SELF_CONTRACT)
+}
+
+pub fn not_self_or_trusted_contract_error() -> StdError {
+    StdError::generic_err(ERR_NOT_SELF_OR_TRUSTED_CONTRACT)
+}
@@ -0,0 +1,30 @@
+use crate::events::IntoEvent;
+use cosmwasm_std::Addr;
+
+pub struct TrustedContractAddedEvent<'a> {
+    pub address: &'a Addr,
+}
+
+impl IntoEvent for TrustedContractAddedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "trusted_contract_added"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![("address".to_string(), self.address.to_string())]
+    }
+}
+
+pub struct TrustedContractRemovedEvent<'a> {
+    pub address: &'a Addr,
+}
+
+impl IntoEvent for TrustedContractRemovedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "trusted_contract_removed"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![("address".to_string(), self.address.to_string())]
+    }
+}
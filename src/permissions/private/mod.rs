@@ -0,0 +1,13 @@
+mod contract;
+mod error;
+mod events;
+mod msg;
+mod trusted_contracts;
+
+pub use contract::*;
+pub use error::{not_self_contract_error, not_self_or_trusted_contract_error};
+pub use msg::*;
+pub use trusted_contracts::{
+    add_trusted_contract, ensure_private, ensure_private_or_trusted, is_trusted_contract,
+    range_trusted_contracts, remove_trusted_contract,
+};
@@ -0,0 +1,138 @@
+use crate::permissions::private::{
+    execute_add_trusted_contract, execute_remove_trusted_contract, query_is_trusted_contract,
+    query_trusted_contracts, QueryIsTrustedContractResponse, QueryTrustedContractsResponse,
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+
+#[cw_serde]
+pub enum TrustedContractsExecuteMsg {
+    AddTrustedContract { addr: Addr },
+
+    RemoveTrustedContract { addr: Addr },
+}
+pub fn handle_trusted_contracts_execute_msg(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    admin_role: &str,
+    msg: TrustedContractsExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        TrustedContractsExecuteMsg::AddTrustedContract { addr } => {
+            execute_add_trusted_contract(deps, env, info, admin_role, addr)
+        }
+        TrustedContractsExecuteMsg::RemoveTrustedContract { addr } => {
+            execute_remove_trusted_contract(deps, env, info, admin_role, addr)
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum TrustedContractsQueryMsg {
+    #[returns(QueryTrustedContractsResponse)]
+    TrustedContracts {
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+
+    #[returns(QueryIsTrustedContractResponse)]
+    IsTrustedContract { addr: Addr },
+}
+pub fn handle_trusted_contracts_query_msg(
+    deps: Deps,
+    msg: TrustedContractsQueryMsg,
+) -> StdResult<Binary> {
+    match msg {
+        TrustedContractsQueryMsg::TrustedContracts { start_after, limit } => {
+            to_json_binary(&query_trusted_contracts(deps, start_after, limit)?)
+        }
+        TrustedContractsQueryMsg::IsTrustedContract { addr } => {
+            to_json_binary(&query_is_trusted_contract(deps, addr)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::error::no_role_error;
+    use crate::permissions::ensure_private_or_trusted;
+    use crate::testing::helpers::{assert_err, deps_with_creator};
+    use cosmwasm_std::from_json;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    const ADMIN_ROLE: &str = "suite_admin";
+
+    #[test]
+    fn test_trusted_contracts_msgs() {
+        let creator = Addr::unchecked("owner".to_string());
+        let user = Addr::unchecked("user".to_string());
+        let child = Addr::unchecked("child".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert_err(
+            &handle_trusted_contracts_execute_msg(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(user.as_str(), &[]),
+                ADMIN_ROLE,
+                TrustedContractsExecuteMsg::AddTrustedContract {
+                    addr: child.clone(),
+                },
+            ),
+            &no_role_error(&user, Some(ADMIN_ROLE)),
+        );
+
+        assert!(handle_trusted_contracts_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            ADMIN_ROLE,
+            TrustedContractsExecuteMsg::AddTrustedContract {
+                addr: child.clone(),
+            },
+        )
+        .is_ok());
+        assert!(ensure_private_or_trusted(deps.as_ref().storage, &env, &child).is_ok());
+
+        let res: QueryTrustedContractsResponse = from_json(
+            handle_trusted_contracts_query_msg(
+                deps.as_ref(),
+                TrustedContractsQueryMsg::TrustedContracts {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res.contracts, vec![child.clone()]);
+
+        assert!(handle_trusted_contracts_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            ADMIN_ROLE,
+            TrustedContractsExecuteMsg::RemoveTrustedContract {
+                addr: child.clone(),
+            },
+        )
+        .is_ok());
+
+        let res: QueryIsTrustedContractResponse = from_json(
+            handle_trusted_contracts_query_msg(
+                deps.as_ref(),
+                TrustedContractsQueryMsg::IsTrustedContract { addr: child },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(!res.is_trusted);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::events::ResponseHandler;
+use crate::permissions::private::error::{
+    not_self_contract_error, not_self_or_trusted_contract_error,
+};
+use crate::permissions::private::events::{TrustedContractAddedEvent, TrustedContractRemovedEvent};
+use crate::storage::StorageSet;
+use cosmwasm_std::{Addr, Env, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+
+// Sibling contracts allowed to call private entry points
+const TRUSTED_CONTRACTS: StorageSet<&Addr> = StorageSet::new("trusted_contracts");
+
+pub fn ensure_private(env: &Env, address: &Addr) -> StdResult<()> {
+    if env.contract.address != address {
+        return Err(not_self_contract_error());
+    }
+
+    Ok(())
+}
+
+pub fn ensure_private_or_trusted(
+    storage: &dyn Storage,
+    env: &Env,
+    address: &Addr,
+) -> StdResult<()> {
+    if env.contract.address != address && !is_trusted_contract(storage, address) {
+        return Err(not_self_or_trusted_contract_error());
+    }
+
+    Ok(())
+}
+
+// Event is only emitted if the contract wasn't trusted yet
+pub fn add_trusted_contract(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    address: &Addr,
+) -> StdResult<()> {
+    if is_trusted_contract(storage, address) {
+        return Ok(());
+    }
+
+    response_handler.add_event(TrustedContractAddedEvent { address });
+    TRUSTED_CONTRACTS.add(storage, &address)
+}
+
+// Event is only emitted if the contract was trusted
+pub fn remove_trusted_contract(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    address: &Addr,
+) {
+    if !is_trusted_contract(storage, address) {
+        return;
+    }
+
+    response_handler.add_event(TrustedContractRemovedEvent { address });
+    TRUSTED_CONTRACTS.remove(storage, &address)
+}
+
+pub fn is_trusted_contract(storage: &dyn Storage, address: &Addr) -> bool {
+    TRUSTED_CONTRACTS.has(storage, &address)
+}
+
+pub fn range_trusted_contracts<'a>(
+    storage: &'a dyn Storage,
+    start_after: Option<&Addr>,
+) -> Box<dyn Iterator<Item = StdResult<Addr>> + 'a> {
+    TRUSTED_CONTRACTS.range(
+        storage,
+        start_after.map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::assert_err;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    #[test]
+    fn test_private_or_trusted() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let child = Addr::unchecked("child".to_string());
+        let other = Addr::unchecked("other".to_string());
+
+        assert!(
+            ensure_private_or_trusted(deps.as_ref().storage, &env, &env.contract.address).is_ok()
+        );
+        assert_err(
+            &ensure_private_or_trusted(deps.as_ref().storage, &env, &child),
+            &not_self_or_trusted_contract_error(),
+        );
+
+        assert!(add_trusted_contract(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            &child
+        )
+        .is_ok());
+        assert!(ensure_private_or_trusted(deps.as_ref().storage, &env, &child).is_ok());
+        assert!(ensure_private_or_trusted(deps.as_ref().storage, &env, &other).is_err());
+
+        // Trusted contracts are not self
+        assert_err(&ensure_private(&env, &child), &not_self_contract_error());
+
+        assert_eq!(
+            range_trusted_contracts(deps.as_ref().storage, None)
+                .collect::<StdResult<Vec<Addr>>>()
+                .unwrap(),
+            vec![child.clone()]
+        );
+
+        // Events are only emitted when the set changes
+        let mut response_handler = ResponseHandler::default();
+        assert!(add_trusted_contract(deps.as_mut().storage, &mut response_handler, &child).is_ok());
+        remove_trusted_contract(deps.as_mut().storage, &mut response_handler, &other);
+        assert!(response_handler.into_response().events.is_empty());
+
+        let mut response_handler = ResponseHandler::default();
+        remove_trusted_contract(deps.as_mut().storage, &mut response_handler, &child);
+        assert_eq!(response_handler.into_response().events.len(), 1);
+        assert!(!is_trusted_contract(deps.as_ref().storage, &child));
+    }
+}
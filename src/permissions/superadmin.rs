@@ -1,17 +1,17 @@
-use cosmwasm_std::{Addr, Deps, Env};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, Storage};
 use cosmwasm_std::{StdError, StdResult};
+use cw_storage_plus::Item;
 
 const ERR_NOT_SUPER_ADMIN: &str = "[FET_ERR_NOT_SUPER_ADMIN] Sender is not a super-admin.";
 
+// Snapshot of the contract admin, None inside means the contract has no admin
+const SUPER_ADMIN_CACHE: Item<Option<Addr>> = Item::new("super_admin_cache");
+
 // Check if the address is admin of the contract
 pub fn is_super_admin(deps: &Deps, env: &Env, address: &Addr) -> StdResult<bool> {
     // Check if the address is specified (opposite of the Everyone case)
-    if let Some(admin_address) = deps
-        .querier
-        .query_wasm_contract_info(&env.contract.address)?
-        .admin
-    {
-        return Ok(address == Addr::unchecked(admin_address));
+    if let Some(admin_address) = query_super_admin(deps, env)? {
+        return Ok(address == admin_address);
     }
     Ok(false)
 }
@@ -24,6 +24,97 @@ pub fn ensure_super_admin(deps: &Deps, env: &Env, address: &Addr) -> StdResult<(
     Ok(())
 }
 
+// Snapshots the current contract admin, call from instantiate and migrate.
+// Admin changes outside of migrate (MsgUpdateAdmin) need another sync.
+pub fn sync_super_admin(deps: DepsMut, env: &Env) -> StdResult<Option<Addr>> {
+    let admin = query_super_admin(&deps.as_ref(), env)?;
+    SUPER_ADMIN_CACHE.save(deps.storage, &admin)?;
+    Ok(admin)
+}
+
+pub fn clear_super_admin_cache(storage: &mut dyn Storage) {
+    SUPER_ADMIN_CACHE.remove(storage)
+}
+
+// Uses the synced admin and falls back to the query if nothing was synced
+pub fn is_super_admin_cached(deps: &Deps, env: &Env, address: &Addr) -> StdResult<bool> {
+    match SUPER_ADMIN_CACHE.may_load(deps.storage)? {
+        Some(admin) => Ok(admin.as_ref() == Some(address)),
+        None => is_super_admin(deps, env, address),
+    }
+}
+
+pub fn ensure_super_admin_cached(deps: &Deps, env: &Env, address: &Addr) -> StdResult<()> {
+    if !is_super_admin_cached(deps, env, address)? {
+        return Err(not_super_admin_error());
+    }
+
+    Ok(())
+}
+
 pub fn not_super_admin_error() -> StdError {
     StdError::generic_err(ERR_NOT_SUPER_ADMIN)
 }
+
+fn query_super_admin(deps: &Deps, env: &Env) -> StdResult<Option<Addr>> {
+    Ok(deps
+        .querier
+        .query_wasm_contract_info(&env.contract.address)?
+        .admin
+        .map(Addr::unchecked))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::AccessControl;
+    use crate::testing::helpers::{assert_err, deps_with_creator};
+    use cosmwasm_std::testing::mock_env;
+
+    #[test]
+    fn test_cached_super_admin() {
+        let creator = Addr::unchecked("creator".to_string());
+        let user = Addr::unchecked("user".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        // Falls back to the query before sync
+        assert!(ensure_super_admin_cached(&deps.as_ref(), &env, &creator).is_ok());
+
+        assert_eq!(
+            sync_super_admin(deps.as_mut(), &env).unwrap(),
+            Some(creator.clone())
+        );
+        assert!(ensure_super_admin_cached(&deps.as_ref(), &env, &creator).is_ok());
+        assert_err(
+            &ensure_super_admin_cached(&deps.as_ref(), &env, &user),
+            &not_super_admin_error(),
+        );
+
+        // Synced value is used instead of the query
+        let mut other_env = mock_env();
+        other_env.contract.address = Addr::unchecked("unknown_contract".to_string());
+        assert!(is_super_admin_cached(&deps.as_ref(), &other_env, &creator).unwrap());
+        assert!(is_super_admin(&deps.as_ref(), &other_env, &creator).is_err());
+
+        // Role checks use the live admin unless the cached variant is used
+        assert!(AccessControl::ensure_has_role_or_superadmin_cached(
+            &deps.as_ref(),
+            &other_env,
+            "role",
+            &creator
+        )
+        .is_ok());
+        assert!(AccessControl::ensure_has_role_or_superadmin(
+            &deps.as_ref(),
+            &other_env,
+            "role",
+            &creator
+        )
+        .is_err());
+
+        clear_super_admin_cache(deps.as_mut().storage);
+        assert!(is_super_admin_cached(&deps.as_ref(), &other_env, &creator).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use crate::events::ResponseHandler;
+use crate::helpers::pagination::query_limit;
+use crate::permissions::threshold::{
+    approve_proposal, create_proposal, execute_proposal, payload_proposal_not_executable_error,
+    proposal, range_proposals, set_threshold_config, threshold_config, Proposal, ThresholdConfig,
+};
+use crate::permissions::{ensure_super_admin, ProposalAction};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw_utils::Expiration;
+
+#[cw_serde]
+pub struct QueryThresholdConfigResponse {
+    pub config: ThresholdConfig,
+}
+
+#[cw_serde]
+pub struct ProposalEntry {
+    pub proposal_id: u64,
+    pub proposal: Proposal,
+}
+
+#[cw_serde]
+pub struct QueryProposalsResponse {
+    pub proposals: Vec<ProposalEntry>,
+}
+
+pub fn query_threshold_config(deps: Deps) -> StdResult<QueryThresholdConfigResponse> {
+    Ok(QueryThresholdConfigResponse {
+        config: threshold_config(deps.storage)?,
+    })
+}
+
+pub fn query_proposal(deps: Deps, proposal_id: u64) -> StdResult<ProposalEntry> {
+    Ok(ProposalEntry {
+        proposal_id,
+        proposal: proposal(deps.storage, proposal_id)?,
+    })
+}
+
+pub fn query_proposals(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<QueryProposalsResponse> {
+    let proposals = range_proposals(deps.storage, start_after)
+        .take(query_limit(limit))
+        .map(|res| {
+            res.map(|(proposal_id, proposal)| ProposalEntry {
+                proposal_id,
+                proposal,
+            })
+        })
+        .collect::<StdResult<Vec<ProposalEntry>>>()?;
+
+    Ok(QueryProposalsResponse { proposals })
+}
+
+pub fn execute_set_threshold_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    member_role: String,
+    threshold: u32,
+) -> StdResult<Response> {
+    ensure_super_admin(&deps.as_ref(), &env, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    set_threshold_config(deps.storage, &mut response_handler, &member_role, threshold)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "set_threshold_config")
+        .add_attribute("sender", info.sender)
+        .add_attribute("member_role", member_role)
+        .add_attribute("threshold", threshold.to_string()))
+}
+
+pub fn execute_create_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_action: ProposalAction,
+    expires: Expiration,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    let proposal_id = create_proposal(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        proposal_action,
+        expires,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "create_proposal")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+pub fn execute_approve_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    let approvals = approve_proposal(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        proposal_id,
+        &info.sender,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "approve_proposal")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("approvals", approvals.to_string()))
+}
+
+// Payload proposals are rejected, contracts acting on them should call execute_proposal
+// and dispatch the returned payload themselves
+pub fn execute_execute_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> StdResult<Response> {
+    if let ProposalAction::Payload { .. } = proposal(deps.storage, proposal_id)?.action {
+        return Err(payload_proposal_not_executable_error(proposal_id));
+    }
+
+    let mut response_handler = ResponseHandler::default();
+
+    execute_proposal(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        proposal_id,
+        &info.sender,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "execute_proposal")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
@@ -0,0 +1,61 @@
+use cosmwasm_std::{Addr, StdError};
+use cw_utils::Expiration;
+
+const ERR_INVALID_THRESHOLD: &str = "[FET_ERR_INVALID_THRESHOLD] Threshold must be at least 1";
+const ERR_THRESHOLD_NOT_CONFIGURED: &str =
+    "[FET_ERR_THRESHOLD_NOT_CONFIGURED] Threshold approvals are not configured";
+const ERR_PROPOSAL_NOT_FOUND: &str = "[FET_ERR_PROPOSAL_NOT_FOUND] Proposal not found";
+const ERR_PROPOSAL_EXPIRED: &str = "[FET_ERR_PROPOSAL_EXPIRED] Proposal is expired";
+const ERR_INVALID_EXPIRATION: &str =
+    "[FET_ERR_INVALID_EXPIRATION] Proposal expiration is already in the past";
+const ERR_PROPOSAL_EXECUTED: &str = "[FET_ERR_PROPOSAL_EXECUTED] Proposal is already executed";
+const ERR_ALREADY_VOTED: &str = "[FET_ERR_ALREADY_VOTED] Address already approved proposal";
+const ERR_THRESHOLD_NOT_REACHED: &str =
+    "[FET_ERR_THRESHOLD_NOT_REACHED] Not enough approvals to execute proposal";
+const ERR_PAYLOAD_PROPOSAL: &str =
+    "[FET_ERR_PAYLOAD_PROPOSAL] Payload proposal has to be executed by the contract";
+
+pub fn invalid_threshold_error() -> StdError {
+    StdError::generic_err(ERR_INVALID_THRESHOLD)
+}
+
+pub fn threshold_not_configured_error() -> StdError {
+    StdError::generic_err(ERR_THRESHOLD_NOT_CONFIGURED)
+}
+
+pub fn proposal_not_found_error(proposal_id: u64) -> StdError {
+    StdError::generic_err(format!("{} {}", ERR_PROPOSAL_NOT_FOUND, proposal_id))
+}
+
+pub fn proposal_expired_error(proposal_id: u64, expires: &Expiration) -> StdError {
+    StdError::generic_err(format!(
+        "{} {} ({})",
+        ERR_PROPOSAL_EXPIRED, proposal_id, expires
+    ))
+}
+
+pub fn invalid_expiration_error(expires: &Expiration) -> StdError {
+    StdError::generic_err(format!("{} ({})", ERR_INVALID_EXPIRATION, expires))
+}
+
+pub fn proposal_already_executed_error(proposal_id: u64) -> StdError {
+    StdError::generic_err(format!("{} {}", ERR_PROPOSAL_EXECUTED, proposal_id))
+}
+
+pub fn already_voted_error(proposal_id: u64, address: &Addr) -> StdError {
+    StdError::generic_err(format!(
+        "{} {}: {}",
+        ERR_ALREADY_VOTED, proposal_id, address
+    ))
+}
+
+pub fn threshold_not_reached_error(approvals: u32, threshold: u32) -> StdError {
+    StdError::generic_err(format!(
+        "{} ({}/{})",
+        ERR_THRESHOLD_NOT_REACHED, approvals, threshold
+    ))
+}
+
+pub fn payload_proposal_not_executable_error(proposal_id: u64) -> StdError {
+    StdError::generic_err(format!("{} {}", ERR_PAYLOAD_PROPOSAL, proposal_id))
+}
@@ -0,0 +1,79 @@
+use crate::events::IntoEvent;
+use cosmwasm_std::Addr;
+use cw_utils::Expiration;
+
+pub struct ThresholdConfigUpdatedEvent<'a> {
+    pub member_role: &'a str,
+    pub threshold: &'a u32,
+}
+
+impl IntoEvent for ThresholdConfigUpdatedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "threshold_config_updated"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("member_role".to_string(), self.member_role.to_string()),
+            ("threshold".to_string(), self.threshold.to_string()),
+        ]
+    }
+}
+
+pub struct ProposalCreatedEvent<'a> {
+    pub proposal_id: &'a u64,
+    pub proposer: &'a Addr,
+    pub expires: &'a Expiration,
+}
+
+impl IntoEvent for ProposalCreatedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "threshold_proposal_created"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("proposal_id".to_string(), self.proposal_id.to_string()),
+            ("proposer".to_string(), self.proposer.to_string()),
+            ("expires".to_string(), self.expires.to_string()),
+        ]
+    }
+}
+
+pub struct ProposalApprovedEvent<'a> {
+    pub proposal_id: &'a u64,
+    pub voter: &'a Addr,
+    pub approvals: &'a u32,
+}
+
+impl IntoEvent for ProposalApprovedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "threshold_proposal_approved"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("proposal_id".to_string(), self.proposal_id.to_string()),
+            ("voter".to_string(), self.voter.to_string()),
+            ("approvals".to_string(), self.approvals.to_string()),
+        ]
+    }
+}
+
+pub struct ProposalExecutedEvent<'a> {
+    pub proposal_id: &'a u64,
+    pub executor: &'a Addr,
+}
+
+impl IntoEvent for ProposalExecutedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "threshold_proposal_executed"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("proposal_id".to_string(), self.proposal_id.to_string()),
+            ("executor".to_string(), self.executor.to_string()),
+        ]
+    }
+}
@@ -0,0 +1,17 @@
+mod contract;
+mod error;
+mod events;
+mod msg;
+mod proposals;
+
+pub use contract::*;
+pub use error::{
+    already_voted_error, invalid_expiration_error, invalid_threshold_error,
+    payload_proposal_not_executable_error, proposal_already_executed_error, proposal_expired_error,
+    proposal_not_found_error, threshold_not_configured_error, threshold_not_reached_error,
+};
+pub use msg::*;
+pub use proposals::{
+    approve_proposal, create_proposal, execute_proposal, has_voted, proposal, range_proposals,
+    set_threshold_config, threshold_config, Proposal, ThresholdConfig,
+};
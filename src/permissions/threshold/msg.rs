@@ -0,0 +1,160 @@
+use crate::permissions::threshold::{
+    execute_approve_proposal, execute_create_proposal, execute_execute_proposal,
+    execute_set_threshold_config, query_proposal, query_proposals, query_threshold_config,
+    ProposalEntry, QueryProposalsResponse, QueryThresholdConfigResponse,
+};
+use crate::permissions::ProposalAction;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw_utils::Expiration;
+
+#[cw_serde]
+pub enum ThresholdExecuteMsg {
+    SetThresholdConfig {
+        member_role: String,
+        threshold: u32,
+    },
+
+    CreateProposal {
+        proposal_action: ProposalAction,
+        expires: Expiration,
+    },
+
+    ApproveProposal {
+        proposal_id: u64,
+    },
+
+    ExecuteProposal {
+        proposal_id: u64,
+    },
+}
+pub fn handle_threshold_execute_msg(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ThresholdExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        ThresholdExecuteMsg::SetThresholdConfig {
+            member_role,
+            threshold,
+        } => execute_set_threshold_config(deps, env, info, member_role, threshold),
+        ThresholdExecuteMsg::CreateProposal {
+            proposal_action,
+            expires,
+        } => execute_create_proposal(deps, env, info, proposal_action, expires),
+        ThresholdExecuteMsg::ApproveProposal { proposal_id } => {
+            execute_approve_proposal(deps, env, info, proposal_id)
+        }
+        ThresholdExecuteMsg::ExecuteProposal { proposal_id } => {
+            execute_execute_proposal(deps, env, info, proposal_id)
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ThresholdQueryMsg {
+    #[returns(QueryThresholdConfigResponse)]
+    ThresholdConfig {},
+
+    #[returns(ProposalEntry)]
+    Proposal { proposal_id: u64 },
+
+    #[returns(QueryProposalsResponse)]
+    Proposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+pub fn handle_threshold_query_msg(deps: Deps, msg: ThresholdQueryMsg) -> StdResult<Binary> {
+    match msg {
+        ThresholdQueryMsg::ThresholdConfig {} => to_json_binary(&query_threshold_config(deps)?),
+        ThresholdQueryMsg::Proposal { proposal_id } => {
+            to_json_binary(&query_proposal(deps, proposal_id)?)
+        }
+        ThresholdQueryMsg::Proposals { start_after, limit } => {
+            to_json_binary(&query_proposals(deps, start_after, limit)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::AccessControl;
+    use crate::events::ResponseHandler;
+    use crate::permissions::threshold::{
+        execute_proposal, payload_proposal_not_executable_error, proposal,
+    };
+    use crate::testing::helpers::{assert_err, deps_with_creator};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    const SIGNER_ROLE: &str = "signer";
+
+    #[test]
+    fn test_payload_proposal_not_executed_by_msg() {
+        let creator = Addr::unchecked("owner".to_string());
+        let signer = Addr::unchecked("signer".to_string());
+
+        let env = mock_env();
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        assert!(handle_threshold_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(creator.as_str(), &[]),
+            ThresholdExecuteMsg::SetThresholdConfig {
+                member_role: SIGNER_ROLE.to_string(),
+                threshold: 1,
+            },
+        )
+        .is_ok());
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            SIGNER_ROLE,
+            &signer
+        )
+        .is_ok());
+
+        let payload = Binary::from(b"set_fee".to_vec());
+        assert!(handle_threshold_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(signer.as_str(), &[]),
+            ThresholdExecuteMsg::CreateProposal {
+                proposal_action: ProposalAction::Payload {
+                    payload: payload.clone(),
+                },
+                expires: Expiration::Never {},
+            },
+        )
+        .is_ok());
+
+        assert_err(
+            &handle_threshold_execute_msg(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(signer.as_str(), &[]),
+                ThresholdExecuteMsg::ExecuteProposal { proposal_id: 0 },
+            ),
+            &payload_proposal_not_executable_error(0),
+        );
+        assert!(!proposal(deps.as_ref().storage, 0).unwrap().executed);
+
+        // Contract dispatches the payload itself
+        assert_eq!(
+            execute_proposal(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                0,
+                &signer,
+            )
+            .unwrap(),
+            ProposalAction::Payload { payload }
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use crate::access_control::AccessControl;
+use crate::events::ResponseHandler;
+use crate::permissions::action::ProposalAction;
+use crate::permissions::threshold::error::{
+    already_voted_error, invalid_expiration_error, invalid_threshold_error,
+    proposal_already_executed_error, proposal_expired_error, proposal_not_found_error,
+    threshold_not_configured_error, threshold_not_reached_error,
+};
+use crate::permissions::threshold::events::{
+    ProposalApprovedEvent, ProposalCreatedEvent, ProposalExecutedEvent, ThresholdConfigUpdatedEvent,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Env, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
+use cw_utils::Expiration;
+
+const THRESHOLD_CONFIG: Item<ThresholdConfig> = Item::new("threshold_config");
+const NEXT_PROPOSAL_ID: Item<u64> = Item::new("threshold_next_proposal_id");
+const PROPOSALS: Map<u64, Proposal> = Map::new("threshold_proposals");
+const VOTES: Map<(u64, &Addr), ()> = Map::new("threshold_votes");
+
+#[cw_serde]
+pub struct ThresholdConfig {
+    // Holders of this role can propose, approve and execute
+    pub member_role: String,
+    // Number of approvals required for execution
+    pub threshold: u32,
+}
+
+#[cw_serde]
+pub struct Proposal {
+    pub proposer: Addr,
+    pub action: ProposalAction,
+    pub expires: Expiration,
+    pub approvals: u32,
+    pub executed: bool,
+}
+
+pub fn set_threshold_config(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    member_role: &str,
+    threshold: u32,
+) -> StdResult<()> {
+    if threshold == 0 {
+        return Err(invalid_threshold_error());
+    }
+
+    response_handler.add_event(ThresholdConfigUpdatedEvent {
+        member_role,
+        threshold: &threshold,
+    });
+    THRESHOLD_CONFIG.save(
+        storage,
+        &ThresholdConfig {
+            member_role: member_role.to_string(),
+            threshold,
+        },
+    )
+}
+
+pub fn threshold_config(storage: &dyn Storage) -> StdResult<ThresholdConfig> {
+    THRESHOLD_CONFIG
+        .may_load(storage)?
+        .ok_or_else(threshold_not_configured_error)
+}
+
+pub fn proposal(storage: &dyn Storage, proposal_id: u64) -> StdResult<Proposal> {
+    PROPOSALS
+        .may_load(storage, proposal_id)?
+        .ok_or_else(|| proposal_not_found_error(proposal_id))
+}
+
+pub fn has_voted(storage: &dyn Storage, proposal_id: u64, address: &Addr) -> bool {
+    VOTES.has(storage, (proposal_id, address))
+}
+
+// Proposer approval is counted automatically
+pub fn create_proposal(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+    proposer: &Addr,
+    action: ProposalAction,
+    expires: Expiration,
+) -> StdResult<u64> {
+    let config = threshold_config(storage)?;
+    AccessControl::ensure_has_role(storage, env, &config.member_role, proposer)?;
+
+    if expires.is_expired(&env.block) {
+        return Err(invalid_expiration_error(&expires));
+    }
+
+    let proposal_id = NEXT_PROPOSAL_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_PROPOSAL_ID.save(storage, &(proposal_id + 1))?;
+
+    response_handler.add_event(ProposalCreatedEvent {
+        proposal_id: &proposal_id,
+        proposer,
+        expires: &expires,
+    });
+    PROPOSALS.save(
+        storage,
+        proposal_id,
+        &Proposal {
+            proposer: proposer.clone(),
+            action,
+            expires,
+            approvals: 0,
+            executed: false,
+        },
+    )?;
+
+    approve_proposal(storage, env, response_handler, proposal_id, proposer)?;
+
+    Ok(proposal_id)
+}
+
+pub fn approve_proposal(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+    proposal_id: u64,
+    voter: &Addr,
+) -> StdResult<u32> {
+    let config = threshold_config(storage)?;
+    AccessControl::ensure_has_role(storage, env, &config.member_role, voter)?;
+
+    let mut proposal = open_proposal(storage, env, proposal_id)?;
+    if has_voted(storage, proposal_id, voter) {
+        return Err(already_voted_error(proposal_id, voter));
+    }
+
+    proposal.approvals += 1;
+
+    response_handler.add_event(ProposalApprovedEvent {
+        proposal_id: &proposal_id,
+        voter,
+        approvals: &proposal.approvals,
+    });
+    VOTES.save(storage, (proposal_id, voter), &())?;
+    PROPOSALS.save(storage, proposal_id, &proposal)?;
+
+    Ok(proposal.approvals)
+}
+
+// Messages are added to the response, payloads are returned for the caller to act on
+pub fn execute_proposal(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+    proposal_id: u64,
+    executor: &Addr,
+) -> StdResult<ProposalAction> {
+    let config = threshold_config(storage)?;
+    AccessControl::ensure_has_role(storage, env, &config.member_role, executor)?;
+
+    let mut proposal = open_proposal(storage, env, proposal_id)?;
+
+    // Approvals of addresses that lost the role don't count
+    let approvals = VOTES
+        .prefix(proposal_id)
+        .keys(storage, None, None, Order::Ascending)
+        .filter(|voter| {
+            voter.as_ref().is_ok_and(|voter| {
+                AccessControl::has_role(storage, env, &config.member_role, voter)
+            })
+        })
+        .count() as u32;
+    if approvals < config.threshold {
+        return Err(threshold_not_reached_error(approvals, config.threshold));
+    }
+
+    proposal.executed = true;
+    PROPOSALS.save(storage, proposal_id, &proposal)?;
+
+    response_handler.add_event(ProposalExecutedEvent {
+        proposal_id: &proposal_id,
+        executor,
+    });
+    if let ProposalAction::Message { msg } = &proposal.action {
+        response_handler.add_message(msg.clone());
+    }
+
+    Ok(proposal.action)
+}
+
+pub fn range_proposals<'a>(
+    storage: &'a dyn Storage,
+    start_after: Option<u64>,
+) -> Box<dyn Iterator<Item = StdResult<(u64, Proposal)>> + 'a> {
+    PROPOSALS.range(
+        storage,
+        start_after.map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    )
+}
+
+fn open_proposal(storage: &dyn Storage, env: &Env, proposal_id: u64) -> StdResult<Proposal> {
+    let proposal = proposal(storage, proposal_id)?;
+    if proposal.executed {
+        return Err(proposal_already_executed_error(proposal_id));
+    }
+    if proposal.expires.is_expired(&env.block) {
+        return Err(proposal_expired_error(proposal_id, &proposal.expires));
+    }
+    Ok(proposal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::error::no_role_error;
+    use crate::testing::helpers::{assert_err, mock_env_with_height};
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::{BankMsg, CosmosMsg, SubMsg};
+
+    const SIGNER_ROLE: &str = "signer";
+
+    #[test]
+    fn test_threshold_proposals() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_with_height(100);
+        let alice = Addr::unchecked("alice".to_string());
+        let bob = Addr::unchecked("bob".to_string());
+        let carol = Addr::unchecked("carol".to_string());
+        let outsider = Addr::unchecked("outsider".to_string());
+
+        assert_err(
+            &set_threshold_config(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                SIGNER_ROLE,
+                0,
+            ),
+            &invalid_threshold_error(),
+        );
+        assert!(set_threshold_config(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            SIGNER_ROLE,
+            2
+        )
+        .is_ok());
+
+        for signer in [&alice, &bob, &carol] {
+            assert!(AccessControl::_grant_role_unrestricted(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                SIGNER_ROLE,
+                signer
+            )
+            .is_ok());
+        }
+
+        let msg: CosmosMsg = BankMsg::Burn { amount: vec![] }.into();
+
+        assert_err(
+            &create_proposal(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &outsider,
+                ProposalAction::Message { msg: msg.clone() },
+                Expiration::AtHeight(110),
+            ),
+            &no_role_error(&outsider, Some(SIGNER_ROLE)),
+        );
+
+        // Already expired proposal is rejected without using up an id
+        assert_err(
+            &create_proposal(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &alice,
+                ProposalAction::Message { msg: msg.clone() },
+                Expiration::AtHeight(env.block.height),
+            ),
+            &invalid_expiration_error(&Expiration::AtHeight(env.block.height)),
+        );
+
+        let proposal_id = create_proposal(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &alice,
+            ProposalAction::Message { msg: msg.clone() },
+            Expiration::AtHeight(110),
+        )
+        .unwrap();
+        assert_eq!(proposal_id, 0);
+        assert_eq!(
+            proposal(deps.as_ref().storage, proposal_id)
+                .unwrap()
+                .approvals,
+            1
+        );
+
+        // Not enough approvals yet
+        assert_err(
+            &execute_proposal(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                proposal_id,
+                &alice,
+            ),
+            &threshold_not_reached_error(1, 2),
+        );
+
+        assert_err(
+            &approve_proposal(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                proposal_id,
+                &alice,
+            ),
+            &already_voted_error(proposal_id, &alice),
+        );
+        assert_eq!(
+            approve_proposal(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                proposal_id,
+                &bob,
+            )
+            .unwrap(),
+            2
+        );
+
+        // Expired proposal can't be executed
+        assert_err(
+            &execute_proposal(
+                deps.as_mut().storage,
+                &mock_env_with_height(110),
+                &mut ResponseHandler::default(),
+                proposal_id,
+                &carol,
+            ),
+            &proposal_expired_error(proposal_id, &Expiration::AtHeight(110)),
+        );
+
+        let mut response_handler = ResponseHandler::default();
+        assert!(execute_proposal(
+            deps.as_mut().storage,
+            &env,
+            &mut response_handler,
+            proposal_id,
+            &carol,
+        )
+        .is_ok());
+        assert_eq!(
+            response_handler.into_response().messages,
+            vec![SubMsg::new(msg)]
+        );
+
+        assert_err(
+            &execute_proposal(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                proposal_id,
+                &carol,
+            ),
+            &proposal_already_executed_error(proposal_id),
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::{Order, StdResult, Storage};
-use cw_storage_plus::{Key, Path, Prefix, PrimaryKey};
+use cw_storage_plus::{Bound, Key, KeyDeserialize, Path, Prefix, PrimaryKey};
 use std::marker::PhantomData;
 
 #[derive(Debug)]
@@ -58,3 +58,24 @@ where
         Prefix::new(self.namespace, &[])
     }
 }
+
+impl<'a, K> StorageSet<'a, K>
+where
+    K: PrimaryKey<'a> + KeyDeserialize,
+    K::Output: 'static,
+{
+    // Unlike get_all, works for borrowed keys such as &Addr
+    pub fn range<'c>(
+        &self,
+        store: &'c dyn Storage,
+        min: Option<Bound<'a, K>>,
+        max: Option<Bound<'a, K>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<K::Output>> + 'c> {
+        Box::new(
+            Prefix::<K, (), K>::new(self.namespace, &[])
+                .range(store, min, max, order)
+                .map(|res| res.map(|(key, _)| key)),
+        )
+    }
+}
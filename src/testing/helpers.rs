@@ -1,7 +1,7 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
     to_json_binary, Addr, ContractInfoResponse, Empty, Env, OwnedDeps, SystemError, SystemResult,
-    WasmQuery,
+    Timestamp, WasmQuery,
 };
 use cosmwasm_std::{BankMsg, Coin, ContractResult as StdContractResult, Response, SubMsg, Uint128};
 
@@ -57,3 +57,9 @@ pub fn mock_env_with_height(height: u64) -> Env {
     env.block.height = height;
     env
 }
+
+pub fn mock_env_with_time(time: Timestamp) -> Env {
+    let mut env = mock_env();
+    env.block.time = time;
+    env
+}
@@ -0,0 +1,162 @@
+use crate::events::ResponseHandler;
+use crate::helpers::pagination::query_limit;
+use crate::permissions::{ensure_private, ProposalAction};
+use crate::timelock::{
+    cancel_operation, execute_operation, operation, payload_operation_not_executable_error,
+    range_operations, schedule_operation, set_timelock_config, timelock_config, TimelockConfig,
+    TimelockOperation,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+#[cw_serde]
+pub struct QueryTimelockConfigResponse {
+    pub config: TimelockConfig,
+}
+
+#[cw_serde]
+pub struct TimelockOperationEntry {
+    pub operation_id: String,
+    pub operation: TimelockOperation,
+}
+
+#[cw_serde]
+pub struct QueryTimelockOperationsResponse {
+    pub operations: Vec<TimelockOperationEntry>,
+}
+
+pub fn query_timelock_config(deps: Deps) -> StdResult<QueryTimelockConfigResponse> {
+    Ok(QueryTimelockConfigResponse {
+        config: timelock_config(deps.storage)?,
+    })
+}
+
+pub fn query_timelock_operation(
+    deps: Deps,
+    operation_id: String,
+) -> StdResult<TimelockOperationEntry> {
+    let operation = operation(deps.storage, &operation_id)?;
+
+    Ok(TimelockOperationEntry {
+        operation_id,
+        operation,
+    })
+}
+
+pub fn query_timelock_operations(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QueryTimelockOperationsResponse> {
+    let operations = range_operations(deps.storage, start_after.as_deref())
+        .take(query_limit(limit))
+        .map(|res| {
+            res.map(|(operation_id, operation)| TimelockOperationEntry {
+                operation_id,
+                operation,
+            })
+        })
+        .collect::<StdResult<Vec<TimelockOperationEntry>>>()?;
+
+    Ok(QueryTimelockOperationsResponse { operations })
+}
+
+// Config can only be changed by a scheduled operation calling back into the contract,
+// the initial one is set with set_timelock_config at instantiation
+pub fn execute_set_timelock_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: TimelockConfig,
+) -> StdResult<Response> {
+    ensure_private(&env, &info.sender)?;
+
+    let mut response_handler = ResponseHandler::default();
+
+    set_timelock_config(deps.storage, &mut response_handler, config)?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "set_timelock_config")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_schedule_operation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operation_id: String,
+    operation_action: ProposalAction,
+    ready_at: u64,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    schedule_operation(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &operation_id,
+        operation_action,
+        ready_at,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "schedule_operation")
+        .add_attribute("sender", info.sender)
+        .add_attribute("operation_id", operation_id)
+        .add_attribute("ready_at", ready_at.to_string()))
+}
+
+pub fn execute_cancel_operation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operation_id: String,
+) -> StdResult<Response> {
+    let mut response_handler = ResponseHandler::default();
+
+    cancel_operation(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &operation_id,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "cancel_operation")
+        .add_attribute("sender", info.sender)
+        .add_attribute("operation_id", operation_id))
+}
+
+// Payload operations are rejected, contracts acting on them should call execute_operation
+// and dispatch the returned payload themselves
+pub fn execute_execute_operation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operation_id: String,
+) -> StdResult<Response> {
+    if let ProposalAction::Payload { .. } = operation(deps.storage, &operation_id)?.action {
+        return Err(payload_operation_not_executable_error(&operation_id));
+    }
+
+    let mut response_handler = ResponseHandler::default();
+
+    execute_operation(
+        deps.storage,
+        &env,
+        &mut response_handler,
+        &info.sender,
+        &operation_id,
+    )?;
+
+    Ok(response_handler
+        .into_response()
+        .add_attribute("action", "execute_operation")
+        .add_attribute("sender", info.sender)
+        .add_attribute("operation_id", operation_id))
+}
@@ -0,0 +1,46 @@
+use cosmwasm_std::StdError;
+
+const ERR_TIMELOCK_NOT_CONFIGURED: &str =
+    "[FET_ERR_TIMELOCK_NOT_CONFIGURED] Timelock is not configured";
+const ERR_DELAY_TOO_SHORT: &str =
+    "[FET_ERR_TIMELOCK_DELAY_TOO_SHORT] Operation can't be ready before block";
+const ERR_DELAY_OVERFLOW: &str =
+    "[FET_ERR_TIMELOCK_DELAY_OVERFLOW] Timelock delay overflows the block height";
+const ERR_OPERATION_SCHEDULED: &str =
+    "[FET_ERR_OPERATION_SCHEDULED] Operation is already scheduled";
+const ERR_OPERATION_NOT_FOUND: &str = "[FET_ERR_OPERATION_NOT_FOUND] Operation not found";
+const ERR_OPERATION_NOT_READY: &str =
+    "[FET_ERR_OPERATION_NOT_READY] Operation can't be executed before block";
+const ERR_PAYLOAD_OPERATION: &str =
+    "[FET_ERR_PAYLOAD_OPERATION] Payload operation has to be executed by the contract";
+
+pub fn timelock_not_configured_error() -> StdError {
+    StdError::generic_err(ERR_TIMELOCK_NOT_CONFIGURED)
+}
+
+pub fn timelock_delay_too_short_error(min_ready_at: u64) -> StdError {
+    StdError::generic_err(format!("{} {}", ERR_DELAY_TOO_SHORT, min_ready_at))
+}
+
+pub fn timelock_delay_overflow_error() -> StdError {
+    StdError::generic_err(ERR_DELAY_OVERFLOW)
+}
+
+pub fn operation_already_scheduled_error(operation_id: &str) -> StdError {
+    StdError::generic_err(format!("{}: {}", ERR_OPERATION_SCHEDULED, operation_id))
+}
+
+pub fn operation_not_found_error(operation_id: &str) -> StdError {
+    StdError::generic_err(format!("{}: {}", ERR_OPERATION_NOT_FOUND, operation_id))
+}
+
+pub fn operation_not_ready_error(operation_id: &str, ready_at: u64) -> StdError {
+    StdError::generic_err(format!(
+        "{} {}: {}",
+        ERR_OPERATION_NOT_READY, ready_at, operation_id
+    ))
+}
+
+pub fn payload_operation_not_executable_error(operation_id: &str) -> StdError {
+    StdError::generic_err(format!("{}: {}", ERR_PAYLOAD_OPERATION, operation_id))
+}
@@ -0,0 +1,87 @@
+use crate::events::helpers::option_to_string;
+use crate::events::IntoEvent;
+use cosmwasm_std::Addr;
+
+pub struct TimelockConfigUpdatedEvent<'a> {
+    pub min_delay: &'a u64,
+    pub proposer_role: &'a str,
+    pub canceller_role: &'a str,
+    pub executor_role: Option<&'a str>,
+}
+
+impl IntoEvent for TimelockConfigUpdatedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "timelock_config_updated"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("min_delay".to_string(), self.min_delay.to_string()),
+            ("proposer_role".to_string(), self.proposer_role.to_string()),
+            (
+                "canceller_role".to_string(),
+                self.canceller_role.to_string(),
+            ),
+            (
+                "executor_role".to_string(),
+                option_to_string(self.executor_role),
+            ),
+        ]
+    }
+}
+
+pub struct OperationScheduledEvent<'a> {
+    pub operation_id: &'a str,
+    pub ready_at: &'a u64,
+    pub scheduled_by: &'a Addr,
+}
+
+impl IntoEvent for OperationScheduledEvent<'_> {
+    fn event_name(&self) -> &str {
+        "timelock_operation_scheduled"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("operation_id".to_string(), self.operation_id.to_string()),
+            ("ready_at".to_string(), self.ready_at.to_string()),
+            ("scheduled_by".to_string(), self.scheduled_by.to_string()),
+        ]
+    }
+}
+
+pub struct OperationCancelledEvent<'a> {
+    pub operation_id: &'a str,
+    pub cancelled_by: &'a Addr,
+}
+
+impl IntoEvent for OperationCancelledEvent<'_> {
+    fn event_name(&self) -> &str {
+        "timelock_operation_cancelled"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("operation_id".to_string(), self.operation_id.to_string()),
+            ("cancelled_by".to_string(), self.cancelled_by.to_string()),
+        ]
+    }
+}
+
+pub struct OperationExecutedEvent<'a> {
+    pub operation_id: &'a str,
+    pub executed_by: &'a Addr,
+}
+
+impl IntoEvent for OperationExecutedEvent<'_> {
+    fn event_name(&self) -> &str {
+        "timelock_operation_executed"
+    }
+
+    fn event_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("operation_id".to_string(), self.operation_id.to_string()),
+            ("executed_by".to_string(), self.executed_by.to_string()),
+        ]
+    }
+}
@@ -0,0 +1,17 @@
+mod contract;
+mod error;
+mod events;
+mod msg;
+mod operations;
+
+pub use contract::*;
+pub use error::{
+    operation_already_scheduled_error, operation_not_found_error, operation_not_ready_error,
+    payload_operation_not_executable_error, timelock_delay_overflow_error,
+    timelock_delay_too_short_error, timelock_not_configured_error,
+};
+pub use msg::*;
+pub use operations::{
+    cancel_operation, execute_operation, operation, range_operations, schedule_operation,
+    set_timelock_config, timelock_config, TimelockConfig, TimelockOperation,
+};
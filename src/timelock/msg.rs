@@ -0,0 +1,212 @@
+use crate::permissions::ProposalAction;
+use crate::timelock::{
+    execute_cancel_operation, execute_execute_operation, execute_schedule_operation,
+    execute_set_timelock_config, query_timelock_config, query_timelock_operation,
+    query_timelock_operations, QueryTimelockConfigResponse, QueryTimelockOperationsResponse,
+    TimelockConfig, TimelockOperationEntry,
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+#[cw_serde]
+pub enum TimelockExecuteMsg {
+    SetTimelockConfig {
+        config: TimelockConfig,
+    },
+
+    ScheduleOperation {
+        operation_id: String,
+        operation_action: ProposalAction,
+        ready_at: u64,
+    },
+
+    CancelOperation {
+        operation_id: String,
+    },
+
+    ExecuteOperation {
+        operation_id: String,
+    },
+}
+pub fn handle_timelock_execute_msg(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: TimelockExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        TimelockExecuteMsg::SetTimelockConfig { config } => {
+            execute_set_timelock_config(deps, env, info, config)
+        }
+        TimelockExecuteMsg::ScheduleOperation {
+            operation_id,
+            operation_action,
+            ready_at,
+        } => execute_schedule_operation(deps, env, info, operation_id, operation_action, ready_at),
+        TimelockExecuteMsg::CancelOperation { operation_id } => {
+            execute_cancel_operation(deps, env, info, operation_id)
+        }
+        TimelockExecuteMsg::ExecuteOperation { operation_id } => {
+            execute_execute_operation(deps, env, info, operation_id)
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum TimelockQueryMsg {
+    #[returns(QueryTimelockConfigResponse)]
+    TimelockConfig {},
+
+    #[returns(TimelockOperationEntry)]
+    TimelockOperation { operation_id: String },
+
+    #[returns(QueryTimelockOperationsResponse)]
+    TimelockOperations {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+pub fn handle_timelock_query_msg(deps: Deps, msg: TimelockQueryMsg) -> StdResult<Binary> {
+    match msg {
+        TimelockQueryMsg::TimelockConfig {} => to_json_binary(&query_timelock_config(deps)?),
+        TimelockQueryMsg::TimelockOperation { operation_id } => {
+            to_json_binary(&query_timelock_operation(deps, operation_id)?)
+        }
+        TimelockQueryMsg::TimelockOperations { start_after, limit } => {
+            to_json_binary(&query_timelock_operations(deps, start_after, limit)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::AccessControl;
+    use crate::events::ResponseHandler;
+    use crate::permissions::not_self_contract_error;
+    use crate::testing::helpers::{assert_err, deps_with_creator, mock_env_with_height};
+    use crate::timelock::{
+        execute_operation, operation, payload_operation_not_executable_error, set_timelock_config,
+    };
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{Addr, BankMsg, SubMsg};
+
+    const PROPOSER_ROLE: &str = "proposer";
+    const CANCELLER_ROLE: &str = "canceller";
+
+    #[test]
+    fn test_execute_operation_msg() {
+        let creator = Addr::unchecked("owner".to_string());
+        let proposer = Addr::unchecked("proposer".to_string());
+
+        let env = mock_env_with_height(100);
+        let env_ready = mock_env_with_height(110);
+        let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+        let config = TimelockConfig {
+            min_delay: 10,
+            proposer_role: PROPOSER_ROLE.to_string(),
+            canceller_role: CANCELLER_ROLE.to_string(),
+            executor_role: None,
+        };
+        assert!(set_timelock_config(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            config.clone()
+        )
+        .is_ok());
+
+        // Even the super-admin can't change the config without going through the timelock
+        assert_err(
+            &handle_timelock_execute_msg(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(creator.as_str(), &[]),
+                TimelockExecuteMsg::SetTimelockConfig {
+                    config: config.clone(),
+                },
+            ),
+            &not_self_contract_error(),
+        );
+        assert!(handle_timelock_execute_msg(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_str(), &[]),
+            TimelockExecuteMsg::SetTimelockConfig { config },
+        )
+        .is_ok());
+        assert!(AccessControl::_grant_role_unrestricted(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            PROPOSER_ROLE,
+            &proposer
+        )
+        .is_ok());
+
+        let msg = BankMsg::Burn { amount: vec![] };
+        let payload = Binary::from(b"set_fee".to_vec());
+        for (operation_id, operation_action) in [
+            (
+                "burn",
+                ProposalAction::Message {
+                    msg: msg.clone().into(),
+                },
+            ),
+            (
+                "set_fee",
+                ProposalAction::Payload {
+                    payload: payload.clone(),
+                },
+            ),
+        ] {
+            assert!(handle_timelock_execute_msg(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(proposer.as_str(), &[]),
+                TimelockExecuteMsg::ScheduleOperation {
+                    operation_id: operation_id.to_string(),
+                    operation_action,
+                    ready_at: 110,
+                },
+            )
+            .is_ok());
+        }
+
+        let res = handle_timelock_execute_msg(
+            deps.as_mut(),
+            env_ready.clone(),
+            mock_info("anyone", &[]),
+            TimelockExecuteMsg::ExecuteOperation {
+                operation_id: "burn".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(msg)]);
+
+        // Payload operation is kept for the contract to execute
+        assert_err(
+            &handle_timelock_execute_msg(
+                deps.as_mut(),
+                env_ready.clone(),
+                mock_info("anyone", &[]),
+                TimelockExecuteMsg::ExecuteOperation {
+                    operation_id: "set_fee".to_string(),
+                },
+            ),
+            &payload_operation_not_executable_error("set_fee"),
+        );
+        assert!(operation(deps.as_ref().storage, "set_fee").is_ok());
+        assert_eq!(
+            execute_operation(
+                deps.as_mut().storage,
+                &env_ready,
+                &mut ResponseHandler::default(),
+                &proposer,
+                "set_fee",
+            )
+            .unwrap(),
+            ProposalAction::Payload { payload }
+        );
+    }
+}
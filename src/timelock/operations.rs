@@ -0,0 +1,348 @@
+use crate::access_control::AccessControl;
+use crate::events::ResponseHandler;
+use crate::permissions::ProposalAction;
+use crate::timelock::error::{
+    operation_already_scheduled_error, operation_not_found_error, operation_not_ready_error,
+    timelock_delay_overflow_error, timelock_delay_too_short_error, timelock_not_configured_error,
+};
+use crate::timelock::events::{
+    OperationCancelledEvent, OperationExecutedEvent, OperationScheduledEvent,
+    TimelockConfigUpdatedEvent,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Env, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
+
+const TIMELOCK_CONFIG: Item<TimelockConfig> = Item::new("timelock_config");
+const OPERATIONS: Map<&str, TimelockOperation> = Map::new("timelock_operations");
+
+#[cw_serde]
+pub struct TimelockConfig {
+    // Minimum number of blocks between scheduling and execution
+    pub min_delay: u64,
+    pub proposer_role: String,
+    pub canceller_role: String,
+    // Anyone can execute ready operations if not set
+    pub executor_role: Option<String>,
+}
+
+#[cw_serde]
+pub struct TimelockOperation {
+    pub action: ProposalAction,
+    pub ready_at: u64,
+    pub scheduled_by: Addr,
+}
+
+pub fn set_timelock_config(
+    storage: &mut dyn Storage,
+    response_handler: &mut ResponseHandler,
+    config: TimelockConfig,
+) -> StdResult<()> {
+    response_handler.add_event(TimelockConfigUpdatedEvent {
+        min_delay: &config.min_delay,
+        proposer_role: &config.proposer_role,
+        canceller_role: &config.canceller_role,
+        executor_role: config.executor_role.as_deref(),
+    });
+    TIMELOCK_CONFIG.save(storage, &config)
+}
+
+pub fn timelock_config(storage: &dyn Storage) -> StdResult<TimelockConfig> {
+    TIMELOCK_CONFIG
+        .may_load(storage)?
+        .ok_or_else(timelock_not_configured_error)
+}
+
+pub fn operation(storage: &dyn Storage, operation_id: &str) -> StdResult<TimelockOperation> {
+    OPERATIONS
+        .may_load(storage, operation_id)?
+        .ok_or_else(|| operation_not_found_error(operation_id))
+}
+
+pub fn schedule_operation(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+    sender: &Addr,
+    operation_id: &str,
+    action: ProposalAction,
+    ready_at: u64,
+) -> StdResult<()> {
+    let config = timelock_config(storage)?;
+    AccessControl::ensure_has_role(storage, env, &config.proposer_role, sender)?;
+
+    let min_ready_at = env
+        .block
+        .height
+        .checked_add(config.min_delay)
+        .ok_or_else(timelock_delay_overflow_error)?;
+    if ready_at < min_ready_at {
+        return Err(timelock_delay_too_short_error(min_ready_at));
+    }
+    if OPERATIONS.has(storage, operation_id) {
+        return Err(operation_already_scheduled_error(operation_id));
+    }
+
+    response_handler.add_event(OperationScheduledEvent {
+        operation_id,
+        ready_at: &ready_at,
+        scheduled_by: sender,
+    });
+    OPERATIONS.save(
+        storage,
+        operation_id,
+        &TimelockOperation {
+            action,
+            ready_at,
+            scheduled_by: sender.clone(),
+        },
+    )
+}
+
+pub fn cancel_operation(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+    sender: &Addr,
+    operation_id: &str,
+) -> StdResult<()> {
+    let config = timelock_config(storage)?;
+    AccessControl::ensure_has_role(storage, env, &config.canceller_role, sender)?;
+
+    // Ensure operation exists
+    operation(storage, operation_id)?;
+
+    response_handler.add_event(OperationCancelledEvent {
+        operation_id,
+        cancelled_by: sender,
+    });
+    OPERATIONS.remove(storage, operation_id);
+
+    Ok(())
+}
+
+// Messages are added to the response, payloads are returned for the caller to act on
+pub fn execute_operation(
+    storage: &mut dyn Storage,
+    env: &Env,
+    response_handler: &mut ResponseHandler,
+    sender: &Addr,
+    operation_id: &str,
+) -> StdResult<ProposalAction> {
+    let config = timelock_config(storage)?;
+    if let Some(executor_role) = &config.executor_role {
+        AccessControl::ensure_has_role(storage, env, executor_role, sender)?;
+    }
+
+    let operation = operation(storage, operation_id)?;
+    if env.block.height < operation.ready_at {
+        return Err(operation_not_ready_error(operation_id, operation.ready_at));
+    }
+
+    response_handler.add_event(OperationExecutedEvent {
+        operation_id,
+        executed_by: sender,
+    });
+    OPERATIONS.remove(storage, operation_id);
+
+    if let ProposalAction::Message { msg } = &operation.action {
+        response_handler.add_message(msg.clone());
+    }
+
+    Ok(operation.action)
+}
+
+pub fn range_operations<'a>(
+    storage: &'a dyn Storage,
+    start_after: Option<&str>,
+) -> Box<dyn Iterator<Item = StdResult<(String, TimelockOperation)>> + 'a> {
+    OPERATIONS.range(
+        storage,
+        start_after.map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access_control::error::no_role_error;
+    use crate::testing::helpers::{assert_err, mock_env_with_height};
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::to_json_binary;
+
+    const PROPOSER_ROLE: &str = "proposer";
+    const CANCELLER_ROLE: &str = "canceller";
+    const OPERATION_ID: &str = "set_fee";
+
+    #[test]
+    fn test_timelock() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_with_height(100);
+        let proposer = Addr::unchecked("proposer".to_string());
+        let canceller = Addr::unchecked("canceller".to_string());
+        let anyone = Addr::unchecked("anyone".to_string());
+
+        assert_err(
+            &timelock_config(deps.as_ref().storage),
+            &timelock_not_configured_error(),
+        );
+        assert!(set_timelock_config(
+            deps.as_mut().storage,
+            &mut ResponseHandler::default(),
+            TimelockConfig {
+                min_delay: 10,
+                proposer_role: PROPOSER_ROLE.to_string(),
+                canceller_role: CANCELLER_ROLE.to_string(),
+                executor_role: None,
+            },
+        )
+        .is_ok());
+
+        for (role, addr) in [(PROPOSER_ROLE, &proposer), (CANCELLER_ROLE, &canceller)] {
+            assert!(AccessControl::_grant_role_unrestricted(
+                deps.as_mut().storage,
+                &mut ResponseHandler::default(),
+                role,
+                addr
+            )
+            .is_ok());
+        }
+
+        let action = ProposalAction::Payload {
+            payload: to_json_binary(&42u32).unwrap(),
+        };
+
+        assert_err(
+            &schedule_operation(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &anyone,
+                OPERATION_ID,
+                action.clone(),
+                110,
+            ),
+            &no_role_error(&anyone, Some(PROPOSER_ROLE)),
+        );
+
+        // Delay is enforced
+        assert_err(
+            &schedule_operation(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &proposer,
+                OPERATION_ID,
+                action.clone(),
+                109,
+            ),
+            &timelock_delay_too_short_error(110),
+        );
+
+        // Ready block has to fit in u64
+        assert_err(
+            &schedule_operation(
+                deps.as_mut().storage,
+                &mock_env_with_height(u64::MAX - 5),
+                &mut ResponseHandler::default(),
+                &proposer,
+                OPERATION_ID,
+                action.clone(),
+                u64::MAX,
+            ),
+            &timelock_delay_overflow_error(),
+        );
+        assert!(schedule_operation(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &proposer,
+            OPERATION_ID,
+            action.clone(),
+            110,
+        )
+        .is_ok());
+        assert_err(
+            &schedule_operation(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &proposer,
+                OPERATION_ID,
+                action.clone(),
+                120,
+            ),
+            &operation_already_scheduled_error(OPERATION_ID),
+        );
+
+        assert_err(
+            &execute_operation(
+                deps.as_mut().storage,
+                &mock_env_with_height(109),
+                &mut ResponseHandler::default(),
+                &anyone,
+                OPERATION_ID,
+            ),
+            &operation_not_ready_error(OPERATION_ID, 110),
+        );
+
+        // Only canceller can cancel
+        assert_err(
+            &cancel_operation(
+                deps.as_mut().storage,
+                &env,
+                &mut ResponseHandler::default(),
+                &proposer,
+                OPERATION_ID,
+            ),
+            &no_role_error(&proposer, Some(CANCELLER_ROLE)),
+        );
+
+        assert_eq!(
+            execute_operation(
+                deps.as_mut().storage,
+                &mock_env_with_height(110),
+                &mut ResponseHandler::default(),
+                &anyone,
+                OPERATION_ID,
+            )
+            .unwrap(),
+            action
+        );
+        assert!(range_operations(deps.as_ref().storage, None)
+            .next()
+            .is_none());
+
+        // Cancelled operation can't be executed
+        assert!(schedule_operation(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &proposer,
+            OPERATION_ID,
+            action,
+            110,
+        )
+        .is_ok());
+        assert!(cancel_operation(
+            deps.as_mut().storage,
+            &env,
+            &mut ResponseHandler::default(),
+            &canceller,
+            OPERATION_ID,
+        )
+        .is_ok());
+        assert_err(
+            &execute_operation(
+                deps.as_mut().storage,
+                &mock_env_with_height(110),
+                &mut ResponseHandler::default(),
+                &anyone,
+                OPERATION_ID,
+            ),
+            &operation_not_found_error(OPERATION_ID),
+        );
+    }
+}
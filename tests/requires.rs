@@ -0,0 +1,114 @@
+// Tests of the #[requires] attribute macro
+
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw_fetch_std::access_control::error::no_role_error;
+use cw_fetch_std::access_control::AccessControl;
+use cw_fetch_std::events::ResponseHandler;
+use cw_fetch_std::pausing::{contract_paused_error, pause_contract, PauseDetails};
+use cw_fetch_std::permissions::{
+    add_trusted_contract, not_self_or_trusted_contract_error, not_super_admin_error,
+    sync_super_admin,
+};
+use cw_fetch_std::requires;
+use cw_fetch_std::testing::helpers::{assert_err, deps_with_creator};
+
+const MINTER_ROLE: &str = "minter";
+
+#[requires(not_paused, role = "minter")]
+fn mint(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    Ok(Response::new().add_attribute("sender", info.sender))
+}
+
+#[requires(role = MINTER_ROLE)]
+fn mint_by_ref(deps: &DepsMut, env: &Env, info: &MessageInfo) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+#[requires(super_admin)]
+fn admin_query(deps: Deps, env: &Env, info: &MessageInfo) -> StdResult<bool> {
+    Ok(true)
+}
+
+#[requires(super_admin_cached)]
+fn cached_admin_query(deps: &Deps, env: &Env, info: &MessageInfo) -> StdResult<bool> {
+    Ok(true)
+}
+
+#[requires(private_or_trusted)]
+fn callback(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+#[test]
+fn test_requires_macro() {
+    let creator = Addr::unchecked("creator".to_string());
+    let minter = Addr::unchecked("minter".to_string());
+
+    let env = mock_env();
+    let mut deps = deps_with_creator(creator.clone(), env.contract.address.clone());
+
+    assert_err(
+        &mint(deps.as_mut(), env.clone(), mock_info(minter.as_str(), &[])),
+        &no_role_error(&minter, Some(MINTER_ROLE)),
+    );
+
+    assert!(AccessControl::_grant_role_unrestricted(
+        deps.as_mut().storage,
+        &mut ResponseHandler::default(),
+        MINTER_ROLE,
+        &minter
+    )
+    .is_ok());
+    assert!(mint(deps.as_mut(), env.clone(), mock_info(minter.as_str(), &[])).is_ok());
+    assert!(mint_by_ref(&deps.as_mut(), &env, &mock_info(minter.as_str(), &[])).is_ok());
+    assert_err(
+        &mint_by_ref(&deps.as_mut(), &env, &mock_info(creator.as_str(), &[])),
+        &no_role_error(&creator, Some(MINTER_ROLE)),
+    );
+
+    assert!(admin_query(deps.as_ref(), &env, &mock_info(creator.as_str(), &[])).unwrap());
+    assert_err(
+        &admin_query(deps.as_ref(), &env, &mock_info(minter.as_str(), &[])),
+        &not_super_admin_error(),
+    );
+
+    // Only the cached variant uses the synced super-admin
+    assert!(sync_super_admin(deps.as_mut(), &env).is_ok());
+    let mut other_env = mock_env();
+    other_env.contract.address = Addr::unchecked("unknown_contract".to_string());
+    assert!(cached_admin_query(
+        &deps.as_ref(),
+        &other_env,
+        &mock_info(creator.as_str(), &[])
+    )
+    .unwrap());
+    assert!(admin_query(deps.as_ref(), &other_env, &mock_info(creator.as_str(), &[])).is_err());
+
+    let child = Addr::unchecked("child".to_string());
+    assert_err(
+        &callback(deps.as_mut(), env.clone(), mock_info(child.as_str(), &[])),
+        &not_self_or_trusted_contract_error(),
+    );
+    assert!(add_trusted_contract(
+        deps.as_mut().storage,
+        &mut ResponseHandler::default(),
+        &child
+    )
+    .is_ok());
+    assert!(callback(deps.as_mut(), env.clone(), mock_info(child.as_str(), &[])).is_ok());
+
+    // Pause check runs before the role check
+    assert!(pause_contract(
+        deps.as_mut().storage,
+        &mut ResponseHandler::default(),
+        env.block.height,
+        None,
+        PauseDetails::default()
+    )
+    .is_ok());
+    assert_err(
+        &mint(deps.as_mut(), env.clone(), mock_info(minter.as_str(), &[])),
+        &contract_paused_error(),
+    );
+}